@@ -0,0 +1,550 @@
+use std::error::Error;
+use std::fs;
+use std::panic::{self, UnwindSafe};
+use std::process;
+use std::time::Instant;
+
+use aoc_helper::answers::{Answers, Verdict};
+use aoc_helper::bench::{self, BenchConfig, Measurement};
+use aoc_helper::history::{self, History, Run};
+use aoc_helper::inputs::{HttpFetcher, InputCache};
+use aoc_helper::parallel;
+use aoc_helper::{Answer, Input, InputLocator, ParseError, Solver};
+use clap::error::ErrorKind;
+use clap::{Args, CommandFactory, Parser, Subcommand, ValueEnum};
+
+type Solve = fn(&str, u8) -> Result<Answer, Box<dyn Error>>;
+type Bench = fn(u8, u8, &Input, &BenchConfig) -> Result<Measurement, ParseError>;
+
+struct Task {
+    day: u8,
+    part: u8,
+    solve: Solve,
+    bench: Bench,
+}
+
+macro_rules! tasks {
+    ($($day:literal => $krate:ident::$solver:ident [$($part:literal),+]),+ $(,)?) => {
+        &[$($(Task { day: $day, part: $part, solve: <$krate::$solver as Solver>::solve, bench: bench::bench::<$krate::$solver> },)+)+]
+    };
+}
+
+const TASKS: &[Task] = tasks![
+    1 => aoc1::Day1 [1, 2],
+    2 => aoc2::Day2 [1, 2],
+    3 => aoc3::Day3 [1, 2],
+    4 => aoc4::Day4 [1, 2],
+    5 => aoc5::Day5 [1, 2],
+    6 => aoc6::Day6 [1, 2],
+    7 => aoc7::Day7 [1, 2],
+    8 => aoc8::Day8 [1, 2],
+    9 => aoc9::Day9 [1, 2],
+    10 => aoc10::Day10 [1, 2],
+    11 => aoc11::Day11 [1, 2],
+    12 => aoc12::Day12 [1, 2],
+    13 => aoc13::Day13 [1, 2],
+    14 => aoc14::Day14 [1, 2],
+    15 => aoc15::Day15 [1, 2],
+    16 => aoc16::Day16 [1, 2],
+    17 => aoc17::Day17 [1, 2],
+    18 => aoc18::Day18 [1, 2],
+    19 => aoc19::Day19 [1, 2],
+    20 => aoc20::Day20 [1, 2],
+    21 => aoc21::Day21 [1, 2],
+    22 => aoc22::Day22 [1, 2],
+    23 => aoc23::Day23 [1, 2],
+    24 => aoc24::Day24 [1, 2],
+    25 => aoc25::Day25 [1],
+];
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2023 runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Threads for days that solve in parallel, defaults to AOC_THREADS or the number of CPUs
+    #[arg(long, global = true, value_parser = clap::value_parser!(u64).range(1..))]
+    threads: Option<u64>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day (optionally a single part) or every day
+    Run(RunArgs),
+    /// Compare answers with the confirmed answers, so refactors can't silently change them
+    Verify(VerifyArgs),
+    /// Measure parse and solve times over repeated runs
+    Bench(BenchArgs),
+    /// Compare saved benchmarks of two commits and flag regressions
+    Compare(CompareArgs),
+}
+
+#[derive(Args)]
+struct InputArgs {
+    /// Directory with the inputs, defaults to AOC_INPUT_DIR or input/ in the workspace root
+    #[arg(long)]
+    input_dir: Option<String>,
+
+    /// Download inputs missing from the input directory into the cache (see AOC_SESSION)
+    #[arg(long)]
+    fetch: bool,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to run (1-25)
+    #[arg(short, long, required_unless_present = "all", value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Part to run, both parts are run if omitted
+    #[arg(short, long, requires = "day", value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Input file, defaults to the day's own input in the input directory
+    #[arg(short, long, requires = "day")]
+    input: Option<String>,
+
+    #[command(flatten)]
+    inputs: InputArgs,
+
+    /// Run every part of every day
+    #[arg(short, long, conflicts_with_all = ["day", "part", "input"])]
+    all: bool,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Day to verify, every day is verified if omitted
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Part to verify, both parts are verified if omitted
+    #[arg(short, long, requires = "day", value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    #[command(flatten)]
+    inputs: InputArgs,
+
+    /// Answers file, defaults to answers.toml in the input directory
+    #[arg(long)]
+    answers: Option<String>,
+
+    /// Record answers that have not been confirmed yet
+    #[arg(long)]
+    record: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Day to benchmark, every day is benchmarked if omitted
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Part to benchmark, both parts are benchmarked if omitted
+    #[arg(short, long, requires = "day", value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    #[command(flatten)]
+    inputs: InputArgs,
+
+    /// Runs before measuring
+    #[arg(long, default_value_t = 3)]
+    warmups: u32,
+
+    /// Measured runs
+    #[arg(short = 'n', long, default_value_t = 10)]
+    iterations: u32,
+
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Write the results to this file instead of stdout
+    #[arg(short, long)]
+    output: Option<String>,
+
+    /// Save the results to the history, keyed by the current git commit
+    #[arg(long)]
+    save: bool,
+
+    /// History file, defaults to bench_history.jsonl in the workspace root
+    #[arg(long)]
+    history: Option<String>,
+}
+
+#[derive(Args)]
+struct CompareArgs {
+    /// Commit (or prefix) to compare against, defaults to the commit saved before HEAD
+    #[arg(long)]
+    base: Option<String>,
+
+    /// Commit (or prefix) to check, defaults to the most recently saved commit
+    #[arg(long)]
+    head: Option<String>,
+
+    /// Slowdown of the median in percent that counts as a regression
+    #[arg(short, long, default_value_t = 10.0)]
+    threshold: f64,
+
+    /// History file, defaults to bench_history.jsonl in the workspace root
+    #[arg(long)]
+    history: Option<String>,
+}
+
+fn history_file(history: &Option<String>) -> String {
+    return match history {
+        Some(file) => file.clone(),
+        None => aoc_helper::workspace_root().join("bench_history.jsonl").to_string_lossy().into_owned(),
+    };
+}
+
+/// Current git commit, suffixed with "-dirty" if there are uncommitted changes
+fn git_commit() -> Result<String, String> {
+    let git = |args: &[&str]| -> Result<String, String> {
+        let output = process::Command::new("git")
+            .args(args)
+            .current_dir(aoc_helper::workspace_root())
+            .output()
+            .map_err(|err| format!("git: {}", err))?;
+        if !output.status.success() {
+            return Err(format!("git {}: {}", args.join(" "), String::from_utf8_lossy(&output.stderr).trim()));
+        }
+
+        return Ok(String::from_utf8_lossy(&output.stdout).trim().to_owned());
+    };
+
+    let commit = git(&["rev-parse", "--short", "HEAD"])?;
+    let dirty = !git(&["status", "--porcelain", "--untracked-files=no"])?.is_empty();
+
+    return Ok(if dirty { format!("{}-dirty", commit) } else { commit });
+}
+
+/// Where to find inputs: an explicit file, the input directory, or else the cache
+struct Inputs {
+    file: Option<String>,
+    locator: InputLocator,
+    cache: InputCache<HttpFetcher>,
+    fetch: bool,
+}
+
+impl Inputs {
+    fn new(file: Option<String>, args: &InputArgs) -> Inputs {
+        return Inputs {
+            file: file,
+            locator: InputLocator::new(args.input_dir.as_deref()),
+            cache: InputCache::from_env(),
+            fetch: args.fetch,
+        };
+    }
+
+    fn resolve(&self, task: &Task) -> Result<String, Box<dyn Error>> {
+        if let Some(file) = &self.file {
+            return Ok(file.clone());
+        }
+
+        return match self.locator.locate(task.day, task.part) {
+            Ok(input) => Ok(input),
+            Err(err) => match self.cache.cached(task.day) {
+                Some(input) => Ok(input),
+                None if self.fetch => Ok(self.cache.get(task.day)?),
+                None => Err(format!("{}\nor in the cache (run with --fetch to download it)", err).into()),
+            },
+        };
+    }
+}
+
+fn select(day: Option<u8>, part: Option<u8>) -> Vec<&'static Task> {
+    let selected: Vec<&Task> = TASKS
+        .iter()
+        .filter(|task| day.is_none() || Some(task.day) == day)
+        .filter(|task| part.is_none() || Some(task.part) == part)
+        .collect();
+
+    // Note: Only happens for parts that are not solved (day 25 part 2), clap already checks the ranges
+    if selected.is_empty() {
+        let task = match (day, part) {
+            (Some(day), Some(part)) => format!("day {} part {}", day, part),
+            (Some(day), None) => format!("day {}", day),
+            _ => String::from("the selection"),
+        };
+        Cli::command().error(ErrorKind::InvalidValue, format!("No such task: {}", task)).exit();
+    }
+
+    return selected;
+}
+
+fn run(task: &Task, inputs: &Inputs) -> bool {
+    let input = match inputs.resolve(task) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Failed: {}", err);
+            return false;
+        }
+    };
+    println!("Day {} part {} ({})", task.day, task.part, input);
+
+    let start = Instant::now();
+    let result = (task.solve)(&input, task.part);
+    let elapsed = start.elapsed();
+
+    return match result {
+        Ok(answer) => {
+            println!("Answer: {}", answer);
+            println!("Elapsed time: {:.2?}", elapsed);
+            true
+        }
+        Err(err) => {
+            eprintln!("Failed: {}", err);
+            false
+        }
+    };
+}
+
+/// Run `fun`, turning a panic into an error message
+fn catch_panic<T>(fun: impl FnOnce() -> Result<T, String> + UnwindSafe) -> Result<T, String> {
+    return match panic::catch_unwind(fun) {
+        Ok(result) => result,
+        Err(payload) => {
+            let message = payload.downcast_ref::<&str>().map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            Err(format!("panicked: {}", message))
+        }
+    };
+}
+
+/// Solve a task, turning both errors and panics into a message
+fn try_solve(task: &Task, inputs: &Inputs) -> Result<Answer, String> {
+    let input = inputs.resolve(task).map_err(|err| err.to_string())?;
+
+    return catch_panic(|| (task.solve)(&input, task.part).map_err(|err| err.to_string()));
+}
+
+fn try_bench(task: &Task, inputs: &Inputs, config: &BenchConfig) -> Result<Measurement, String> {
+    let file = inputs.resolve(task).map_err(|err| err.to_string())?;
+    let input = Input::read(&file).map_err(|err| err.to_string())?;
+
+    return catch_panic(|| (task.bench)(task.day, task.part, &input, config).map_err(|err| err.to_string()));
+}
+
+fn run_bench(args: &BenchArgs) -> bool {
+    let inputs = Inputs::new(None, &args.inputs);
+    let config = BenchConfig { warmups: args.warmups, iterations: args.iterations };
+
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut measurements = Vec::new();
+    let mut ok = true;
+    for task in select(args.day, args.part) {
+        match try_bench(task, &inputs, &config) {
+            Ok(measurement) => {
+                if let Format::Text = args.format {
+                    println!("Day {} part {}: {}", task.day, task.part, measurement.answer);
+                    println!("  parse: {}", measurement.parse);
+                    println!("  solve: {}", measurement.solve);
+                }
+                measurements.push(measurement);
+            }
+            Err(err) => {
+                eprintln!("Day {} part {}: failed, {}", task.day, task.part, err);
+                ok = false;
+            }
+        }
+    }
+
+    let _ = panic::take_hook();
+
+    if args.save && !measurements.is_empty() {
+        let file = history_file(&args.history);
+        let saved = git_commit().and_then(|commit| {
+            let run = Run { commit: commit, timestamp: chrono::Local::now().to_rfc3339(), measurements: measurements.clone() };
+            History::append(&file, &run).map_err(|err| err.to_string())?;
+            Ok(run.commit)
+        });
+
+        match saved {
+            Ok(commit) => eprintln!("Saved to {} as {}", file, commit),
+            Err(err) => {
+                eprintln!("Failed to save: {}", err);
+                ok = false;
+            }
+        }
+    }
+
+    let output = match args.format {
+        Format::Text => return ok,
+        Format::Json => bench::to_json(&measurements) + "\n",
+        Format::Csv => bench::to_csv(&measurements),
+    };
+
+    match &args.output {
+        Some(file) => {
+            if let Err(err) = fs::write(file, output) {
+                eprintln!("Failed: {}: {}", file, err);
+                return false;
+            }
+        }
+        None => print!("{}", output),
+    }
+
+    return ok;
+}
+
+fn verify(args: &VerifyArgs) -> bool {
+    let inputs = Inputs::new(None, &args.inputs);
+    let answers_file = match &args.answers {
+        Some(file) => file.clone(),
+        None => inputs.locator.dir().join("answers.toml").to_string_lossy().into_owned(),
+    };
+    let mut answers = match Answers::load(&answers_file) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("Failed: {}", err);
+            return false;
+        }
+    };
+
+    // Note: Panics are reported per task below, the default hook would clutter the report
+    panic::set_hook(Box::new(|_| {}));
+
+    let (mut passed, mut changed, mut failed, mut unconfirmed, mut recorded) = (0, 0, 0, 0, 0);
+    for task in select(args.day, args.part) {
+        let result = try_solve(task, &inputs);
+        let status = match result {
+            Ok(answer) => match answers.check(task.day, task.part, &answer) {
+                Verdict::Pass => {
+                    passed += 1;
+                    format!("pass ({})", answer)
+                }
+                Verdict::Changed { expected } => {
+                    changed += 1;
+                    format!("CHANGED, expected {} but got {}", expected, answer)
+                }
+                Verdict::Unconfirmed if args.record && answer != Answer::Unsolved => {
+                    recorded += 1;
+                    let status = format!("recorded ({})", answer);
+                    answers.insert(task.day, task.part, answer);
+                    status
+                }
+                Verdict::Unconfirmed => {
+                    unconfirmed += 1;
+                    format!("unconfirmed ({})", answer)
+                }
+            },
+            Err(err) => {
+                failed += 1;
+                format!("FAIL, {}", err)
+            }
+        };
+
+        println!("Day {} part {}: {}", task.day, task.part, status);
+    }
+
+    let _ = panic::take_hook();
+    println!("{} passed, {} changed, {} failed, {} unconfirmed, {} recorded", passed, changed, failed, unconfirmed, recorded);
+
+    if args.record {
+        if let Err(err) = answers.save(&answers_file) {
+            eprintln!("Failed: {}", err);
+            return false;
+        }
+    }
+
+    return changed == 0 && failed == 0;
+}
+
+fn run_compare(args: &CompareArgs) -> bool {
+    let file = history_file(&args.history);
+    let history = match History::load(&file) {
+        Ok(history) => history,
+        Err(err) => {
+            eprintln!("Failed: {}", err);
+            return false;
+        }
+    };
+    let commits = history.commits();
+
+    let head = match &args.head {
+        Some(prefix) => history.find_commit(prefix),
+        None => commits.last().copied(),
+    };
+    let head = match head {
+        Some(head) => head,
+        None => {
+            eprintln!("Failed: no saved benchmarks for {} in {}", args.head.as_deref().unwrap_or("any commit"), file);
+            return false;
+        }
+    };
+    let base = match &args.base {
+        Some(prefix) => history.find_commit(prefix),
+        None => commits.iter().rev().skip_while(|commit| **commit != head).nth(1).copied(),
+    };
+    let base = match base {
+        Some(base) => base,
+        None => {
+            eprintln!("Failed: no saved benchmarks to compare {} against in {}", head, file);
+            return false;
+        }
+    };
+
+    println!("Comparing {} (base) with {} (head), threshold {}%", base, head, args.threshold);
+
+    let mut regressions = 0;
+    for comparison in history::compare(&history, base, head) {
+        let stages = comparison.regressions(args.threshold);
+        let (base_solve, head_solve) = (comparison.base.1, comparison.head.1);
+        println!(
+            "Day {} part {}: solve {:.2?} -> {:.2?} ({:+.1}%), parse {:+.1}%{}",
+            comparison.day,
+            comparison.part,
+            base_solve.median(),
+            head_solve.median(),
+            history::Comparison::change(&base_solve, &head_solve),
+            history::Comparison::change(&comparison.base.0, &comparison.head.0),
+            if stages.is_empty() { String::new() } else { format!(" REGRESSION ({})", stages.join(", ")) }
+        );
+
+        if !stages.is_empty() {
+            regressions += 1;
+        }
+    }
+
+    println!("{} regression(s)", regressions);
+
+    return regressions == 0;
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    if let Some(threads) = cli.threads {
+        parallel::set_threads(threads as usize);
+    }
+
+    let ok = match cli.command {
+        Command::Run(args) => {
+            let day = if args.all { None } else { args.day };
+            let inputs = Inputs::new(args.input.clone(), &args.inputs);
+
+            let mut ok = true;
+            for task in select(day, args.part) {
+                ok &= run(task, &inputs);
+            }
+            ok
+        }
+        Command::Verify(args) => verify(&args),
+        Command::Bench(args) => run_bench(&args),
+        Command::Compare(args) => run_compare(&args),
+    };
+
+    if !ok {
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,36 @@
+pub mod task1;
+pub mod task2;
+pub mod utils;
+
+use aoc_helper::{Answer, Input, ParseError, Solver};
+
+use crate::utils::read_calibration_vals;
+
+pub struct Day1 {
+    calibration_vals: Option<Vec<i32>>,
+    calibration_vals_with_chars: Vec<i32>
+}
+
+impl Solver for Day1 {
+    fn parse_input(input: &Input) -> Result<Self, ParseError> {
+        // Note: Parsed first, so a line without any (spelled out) digit is an error for both parts
+        let calibration_vals_with_chars = read_calibration_vals(input, true)?;
+
+        // Note: Part 1 can only fail on lines that spell out all their digits, like some lines of the part 2 example.
+        // That input is fine, there just is no part 1 answer for it
+        let calibration_vals = read_calibration_vals(input, false).ok();
+
+        return Ok(Day1 { calibration_vals: calibration_vals, calibration_vals_with_chars: calibration_vals_with_chars });
+    }
+
+    fn part1(&self) -> Answer {
+        return match &self.calibration_vals {
+            Some(calibration_vals) => task1::solve(calibration_vals).into(),
+            None => Answer::Unsolved
+        };
+    }
+
+    fn part2(&self) -> Answer {
+        return task2::solve(&self.calibration_vals_with_chars).into();
+    }
+}
@@ -1,14 +1,6 @@
-mod task1;
-mod task2;
-mod utils;
-use std::time::Instant;
+use aoc1::{task1, task2};
 
 fn main() {
-    let before = Instant::now();
-    task1::run_task();
-    println!("Elapsed time: {:.2?}", before.elapsed());
-
-    let before2 = Instant::now();
-    task2::run_task();
-    println!("Elapsed time: {:.2?}", before2.elapsed());
+    aoc_helper::benchmark(|| task1::run_task(&aoc_helper::input_path(1, 1)));
+    aoc_helper::benchmark(|| task2::run_task(&aoc_helper::input_path(1, 2)));
 }
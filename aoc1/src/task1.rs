@@ -1,12 +1,23 @@
-use crate::utils::read_calibration_vals;
+use aoc_helper::Solver;
+
+use crate::Day1;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::read_calibration_vals;
+    use aoc_helper::{Answer, Input};
+
+    const EXAMPLE_INPUT: &str = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+";
 
     #[test]
     fn test_task_1_example_input() {
-        let calibration_vals = read_calibration_vals("../input/1_1_example_input.txt", false);
+        let calibration_vals = read_calibration_vals(&Input::new(EXAMPLE_INPUT), false).unwrap();
 
         assert_eq!(calibration_vals, vec![12, 38, 15, 77]);
 
@@ -14,11 +25,25 @@ mod tests {
 
         assert_eq!(calibration_vals_sum, 142);
     }
+
+    #[test]
+    fn test_parse_errors() {
+        // Lines of the part 2 example without digits only leave part 1 unsolved
+        let day = Day1::parse_input(&Input::new("two1nine\neightwothree\n")).unwrap();
+        assert_eq!(day.part1(), Answer::Unsolved);
+        assert_eq!(day.part2(), Answer::from(29 + 83));
+
+        let error = Day1::parse_input(&Input::new("1abc2\nnope\n")).err().unwrap();
+        assert_eq!(error.to_string(), "<input>:2:1: expected a line containing a digit, found \"nope\"");
+    }
+}
+
+pub fn solve(calibration_vals: &Vec<i32>) -> i32 {
+    return calibration_vals.iter().sum();
 }
 
-pub fn run_task() {
-    let calibration_vals = read_calibration_vals("input/1_1_input.txt", false);
-    let calibration_vals_sum: i32 = calibration_vals.iter().sum();
+pub fn run_task(file: &str) {
+    let day = Day1::parse_file(file).unwrap();
 
-    println!("Calibration values sum are {}", calibration_vals_sum);
-}
\ No newline at end of file
+    println!("Calibration values sum are {}", day.part1());
+}
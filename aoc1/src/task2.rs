@@ -1,12 +1,25 @@
-use crate::utils::read_calibration_vals;
+use aoc_helper::Solver;
+
+use crate::Day1;
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use crate::utils::read_calibration_vals;
+    use aoc_helper::Input;
+
+    const EXAMPLE_INPUT: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+";
 
     #[test]
     fn test_task_2_example_input() {
-        let calibration_vals = read_calibration_vals("../input/1_2_example_input.txt", true);
+        let calibration_vals = read_calibration_vals(&Input::new(EXAMPLE_INPUT), true).unwrap();
 
         assert_eq!(calibration_vals, vec![29, 83, 13, 24, 42, 14, 76]);
 
@@ -16,9 +29,12 @@ mod tests {
     }
 }
 
-pub fn run_task() {
-    let calibration_vals = read_calibration_vals("input/1_2_input.txt", true);
-    let calibration_vals_sum: i32 = calibration_vals.iter().sum();
+pub fn solve(calibration_vals: &Vec<i32>) -> i32 {
+    return calibration_vals.iter().sum();
+}
 
-    println!("Calibration values sum are {}", calibration_vals_sum);
-}
\ No newline at end of file
+pub fn run_task(file: &str) {
+    let day = Day1::parse_file(file).unwrap();
+
+    println!("Calibration values sum are {}", day.part2());
+}
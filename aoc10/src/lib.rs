@@ -0,0 +1,25 @@
+pub mod task1;
+pub mod task2;
+pub mod utils;
+
+use aoc_helper::{Answer, Input, ParseError, Solver};
+
+use crate::utils::PipeSketch;
+
+pub struct Day10 {
+    pipe_sketch: PipeSketch
+}
+
+impl Solver for Day10 {
+    fn parse_input(input: &Input) -> Result<Self, ParseError> {
+        return Ok(Day10 { pipe_sketch: PipeSketch::parse(input)? });
+    }
+
+    fn part1(&self) -> Answer {
+        return task1::solve(&self.pipe_sketch).into();
+    }
+
+    fn part2(&self) -> Answer {
+        return task2::solve(&self.pipe_sketch).into();
+    }
+}
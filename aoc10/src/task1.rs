@@ -1,19 +1,39 @@
+use aoc_helper::Solver;
+
+use crate::Day10;
 use crate::utils::PipeSketch;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_helper::Input;
+
+    const EXAMPLE_INPUT_1: &str = "\
+-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF
+";
+
+    const EXAMPLE_INPUT_2: &str = "\
+7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ
+";
 
     #[test]
     fn test_example_input() {
-        let pipe_sketch_1 = PipeSketch::parse("../input/10_1_example_input_1_hard.txt");
+        let pipe_sketch_1 = PipeSketch::parse(&Input::new(EXAMPLE_INPUT_1)).unwrap();
         let main_loop_1 = pipe_sketch_1.get_main_loop();
 
         let max_distance_1 = (main_loop_1.len() - 1).div_ceil(2);
 
         assert_eq!(max_distance_1, 4);
 
-        let pipe_sketch_2 = PipeSketch::parse("../input/10_1_example_input_2_hard.txt");
+        let pipe_sketch_2 = PipeSketch::parse(&Input::new(EXAMPLE_INPUT_2)).unwrap();
         let main_loop_2 = pipe_sketch_2.get_main_loop();
 
         let max_distance_2 = (main_loop_2.len() - 1).div_ceil(2);
@@ -22,11 +42,14 @@ mod tests {
     }
 }
 
-pub fn run_task() {
-    let pipe_sketch = PipeSketch::parse("input/10_1_input.txt");
+pub fn solve(pipe_sketch: &PipeSketch) -> usize {
     let main_loop = pipe_sketch.get_main_loop();
 
-    let max_distance = (main_loop.len() - 1).div_ceil(2);
+    return (main_loop.len() - 1).div_ceil(2);
+}
+
+pub fn run_task(file: &str) {
+    let day = Day10::parse_file(file).unwrap();
 
-    println!("Max distance is: {}", max_distance);
-}
\ No newline at end of file
+    println!("Max distance is: {}", day.part1());
+}
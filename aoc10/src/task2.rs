@@ -1,14 +1,31 @@
+use aoc_helper::Solver;
+
+use crate::Day10;
 use crate::utils::{PipeSketch, Tile};
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_helper::Input;
+
+    const EXAMPLE_INPUT_4: &str = "\
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+";
 
     #[test]
     fn test_example_input() {
         // ../input/10_2_example_input_2.txt
         // ../input/10_1_input.txt
-        let mut pipe_sketch_1 = PipeSketch::parse("../input/10_2_example_input_4.txt");
+        let mut pipe_sketch_1 = PipeSketch::parse(&Input::new(EXAMPLE_INPUT_4)).unwrap();
         let main_loop_1 = pipe_sketch_1.get_main_loop();
 
         //PipeSketch::print_overlay(&pipe_sketch_1.map, &main_loop_1);
@@ -38,40 +55,39 @@ mod tests {
     }
 }
 
-pub fn run() {
-    let mut pipe_sketch_1 = PipeSketch::parse("input/10_1_input.txt");
-    let main_loop_1 = pipe_sketch_1.get_main_loop();
-
-    let main_loop_1_exp = Tile::expand_tiles(&main_loop_1);
-    pipe_sketch_1.create_expanded_map();
-
-    PipeSketch::add_overlay_to_exp_map(&mut pipe_sketch_1, &main_loop_1_exp);
-    pipe_sketch_1.fill_reachable_tiles_ext_map();
-
-    PipeSketch::print_map(&pipe_sketch_1.expanded_map);
-    
-    pipe_sketch_1.shrink_expanded_map();
+fn count_enclosed_tiles(mut pipe_sketch: PipeSketch) -> usize {
+    let main_loop = pipe_sketch.get_main_loop();
+    let main_loop_exp = Tile::expand_tiles(&main_loop);
 
-    println!("");
-    PipeSketch::print_map(&pipe_sketch_1.map);
+    pipe_sketch.create_expanded_map();
+    PipeSketch::add_overlay_to_exp_map(&mut pipe_sketch, &main_loop_exp);
+    pipe_sketch.fill_reachable_tiles_ext_map();
+    // PipeSketch::print_map(&pipe_sketch.expanded_map);
 
-    let empty_spaces = pipe_sketch_1.count_empty_spaces();
+    pipe_sketch.shrink_expanded_map();
+    // PipeSketch::print_map(&pipe_sketch.map);
 
-    println!("Empty spaces: {}", empty_spaces);
+    return pipe_sketch.count_empty_spaces();
 }
 
-pub fn run_task() {
+pub fn solve(pipe_sketch: &PipeSketch) -> usize {
     use std::thread;
+
     const STACK_SIZE: usize = 16 * 1024 * 1024;
 
-    // Spawn thread with explicit stack size
+    // Spawn thread with explicit stack size (filling the expanded map is recursive)
+    let pipe_sketch = pipe_sketch.clone();
     let child = thread::Builder::new()
         .stack_size(STACK_SIZE)
-        .spawn(run)
+        .spawn(move || count_enclosed_tiles(pipe_sketch))
         .unwrap();
 
     // Wait for thread to join
-    child.join().unwrap();
+    return child.join().unwrap();
+}
 
+pub fn run_task(file: &str) {
+    let day = Day10::parse_file(file).unwrap();
 
-}
\ No newline at end of file
+    println!("Empty spaces: {}", day.part2());
+}
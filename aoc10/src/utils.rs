@@ -1,29 +1,25 @@
-use std::{collections::{VecDeque, HashMap}, usize};
-
-#[derive(Debug)]
-#[derive(PartialEq)]
-enum Direction {
-    North,
-    South,
-    West,
-    East
-}
+use std::collections::VecDeque;
+
+use aoc_helper::{Input, ParseError};
+use aoc_helper::geom::{Direction, Pos, Vec2};
+use aoc_helper::grid::Grid;
 
 #[derive(Clone)]
 pub struct Tile {
-    pos: (usize, usize),
+    pos: Pos,
     c: char
 }
 
+#[derive(Clone)]
 pub struct PipeSketch {
-    pub map: Vec<Vec<char>>,
-    pub expanded_map: Vec<Vec<char>>,
-    start_pos: (usize, usize)
+    pub map: Grid<char>,
+    pub expanded_map: Grid<char>,
+    start_pos: Pos
 }
 
 impl Tile {
     fn expand_tile(tile: &mut Tile) {
-        tile.pos = (tile.pos.0 * 2 + 1, tile.pos.1 * 2 + 1)
+        tile.pos = Pos::new(tile.pos.x * 2 + 1, tile.pos.y * 2 + 1)
     }
 
     pub fn expand_tiles(tiles: &VecDeque<Tile>) -> VecDeque<Tile> {
@@ -44,9 +40,8 @@ impl Tile {
 
             let next_tile = next_tile_wrapped.unwrap();
 
-            let diff = (next_tile.pos.0 as isize - (tile.pos.0 as isize), next_tile.pos.1 as isize - (tile.pos.1 as isize));
-            let new_pos_isize = (tile.pos.0 as isize + diff.0 / 2, tile.pos.1 as isize + diff.1 / 2);
-            let new_pos = (new_pos_isize.0 as usize, new_pos_isize.1 as usize);
+            let diff = next_tile.pos - tile.pos;
+            let new_pos = tile.pos.checked_add(Vec2::new(diff.x / 2, diff.y / 2)).unwrap();
 
             interpolated_tiles.push_back(tile.clone());
             interpolated_tiles.push_back(Tile { pos: new_pos, c: tile.c });
@@ -58,124 +53,49 @@ impl Tile {
     }
 }
 
-impl Direction {
-    fn determine_direction(curr_coords: &(usize, usize), next_coords: &(usize, usize)) -> Direction {
-        if curr_coords.0 > next_coords.0 {
-            return Direction::North
-        } else if curr_coords.0 < next_coords.0 {
-            return Direction::South;
-        } else if curr_coords.1 > next_coords.1 {
-            return Direction::West;
-        } else if curr_coords.1 < next_coords.1 {
-            return Direction::East;
+impl PipeSketch {
+    // The two directions a pipe connects
+    fn pipe_openings(node: char) -> &'static [Direction] {
+        match node {
+            '|' => &[Direction::North, Direction::South],
+            '-' => &[Direction::East, Direction::West],
+            'L' => &[Direction::North, Direction::East],
+            'J' => &[Direction::North, Direction::West],
+            '7' => &[Direction::South, Direction::West],
+            'F' => &[Direction::South, Direction::East],
+            _ => &[]
         }
-
-        panic!("Unkown direction");
     }
-}
-
-struct TestNode {
-    visited: bool,
-    directions_to_test: Vec<Direction> // TODO: remove this vector
-}
-
-impl PipeSketch {
-    fn get_next_node_coords(curr_coords: &(usize, usize), next_coords: &(usize, usize), node: char) -> (usize, usize) {
-        let mut res = (next_coords.0, next_coords.1);
 
+    fn get_next_node_coords(curr_coords: &Pos, next_coords: &Pos, node: char) -> Pos {
         // Direction we are moving in from curr_coords -> next_coords
-        let direction = Direction::determine_direction(curr_coords, next_coords);
+        let direction = Direction::ALL.into_iter().find(|d| curr_coords.checked_add(d.offset()) == Some(*next_coords)).expect("Unkown direction");
         //println!("Direction: {:?}", direction);
-        match node {
-            '|' => {
-                if direction == Direction::South {
-                    res.0 += 1;
-                } else if direction == Direction::North {
-                    res.0 -= 1;
-                } else {
-                    panic!();
-                }
-            }
-            '-' => {
-                if direction == Direction::East {
-                    res.1 += 1;
-                } else if direction == Direction::West {
-                    res.1 -= 1;
-                } else {
-                    panic!();
-                }
-            }
-            'L' => {
-                if direction == Direction::South {
-                    res.1 += 1;
-                } else if direction == Direction::West {
-                    res.0 -= 1;
-                } else {
-                    panic!();
-                }
-            }
-            'J' => {
-                if direction == Direction::South {
-                    res.1 -= 1;
-                } else if direction == Direction::East {
-                    res.0 -= 1;
-                } else {
-                    panic!();
-                }
-            }
-            '7' => {
-                if direction == Direction::East {
-                    res.0 += 1;
-                } else if direction == Direction::North {
-                    res.1 -= 1;
-                } else {
-                    panic!();
-                }
-            }
-            'F' => {
-                if direction == Direction::North {
-                    res.1 += 1;
-                } else if direction == Direction::West {
-                    res.0 += 1;
-                } else {
-                    panic!();
-                }
-            }
-            _ => panic!("Unexpected node {}", node)
+
+        let openings = Self::pipe_openings(node);
+        if openings.is_empty() {
+            panic!("Unexpected node {}", node);
         }
 
-        return res;
+        // Leave the pipe through the opening we did not enter through
+        let entered_from = direction.reverse();
+        if !openings.contains(&entered_from) {
+            panic!("Can not enter {} going {:?}", node, direction);
+        }
+        let leave_direction = openings.iter().find(|d| **d != entered_from).unwrap();
+
+        return next_coords.checked_add(leave_direction.offset()).unwrap();
     }
 
-    fn find_next_from_start_nodes(&self) -> Vec<(usize, usize)> {
+    fn find_next_from_start_nodes(&self) -> Vec<Pos> {
         let mut next_nodes = Vec::new();
-        let sp = self.start_pos;
 
-        if sp.0 > 0 { // North
-            let node = self.map[sp.0 - 1][sp.1];
-            if node == '|' || node == '7' || node == 'F' {
-                next_nodes.push((sp.0 - 1, sp.1))
-            }
-        }
-        
-        if sp.0 < self.map.len() - 1 { // South
-            let node = self.map[sp.0 + 1][sp.1];
-            if node == '|' || node == 'L' || node == 'J' {
-                next_nodes.push((sp.0 + 1, sp.1))
-            }
-        }
-        
-        if sp.1 > 0 { // West
-            let node = self.map[sp.0][sp.1 - 1];
-            if node == '-' || node == 'L' || node == 'F' {
-                next_nodes.push((sp.0, sp.1 - 1))
-            }
-        }
-        
-        if sp.1 < self.map.first().unwrap().len() - 1 { // East
-            let node = self.map[sp.0][sp.1 + 1];
-            if node == '-' || node == 'J' || node == '7' {
-                next_nodes.push((sp.0, sp.1 + 1))
+        for direction in Direction::ALL {
+            if let Some(pos) = self.map.step(self.start_pos, direction) {
+                // The pipe has to connect back to the start
+                if Self::pipe_openings(self.map[pos]).contains(&direction.reverse()) {
+                    next_nodes.push(pos);
+                }
             }
         }
 
@@ -195,7 +115,7 @@ impl PipeSketch {
         let mut curr_node_coords = self.start_pos;
         let mut next_node_coords = next_from_start_nodes.pop().unwrap();
         while next_node_coords != self.start_pos {
-            let next_node = self.map[next_node_coords.0][next_node_coords.1];
+            let next_node = self.map[next_node_coords];
             //println!("Curr_node_coords: {:?}, Next_node_coords: {:?}, next_node {}", curr_node_coords, next_node_coords, next_node);
 
             main_loop.push_back(Tile { pos: next_node_coords, c: next_node }); // TODO: Or push front?
@@ -208,61 +128,23 @@ impl PipeSketch {
         return main_loop;
     }
 
-    fn find_start_pos(map: &Vec<Vec<char>>) -> (usize, usize) {
-        for y in 0..map.len() {
-            for x in 0..map.first().unwrap().len() {
-                if map[y][x] == 'S' {
-                    return (y, x);
-                }
-            }
-        }
-
-        return (0, 0);
+    fn find_start_pos(map: &Grid<char>) -> Pos {
+        return Pos::from(map.position(|c| *c == 'S').unwrap_or((0, 0)));
     }
 
-    fn test_node(map: &mut Vec<Vec<char>>, test_nodes: &mut Vec<Vec<TestNode>>, pos: (usize, usize)) {
-        let height = test_nodes.len();
-        let width = test_nodes.first().unwrap().len();
-
-        test_nodes[pos.0][pos.1].visited = true;
-        map[pos.0][pos.1] = 'O';
-
-        let directions = vec![Direction::North, Direction::South, Direction::West, Direction::East];
+    fn test_node(map: &mut Grid<char>, visited: &mut Grid<bool>, pos: Pos) {
+        visited[pos] = true;
+        map[pos] = 'O';
 
         //println!("{:?}", pos);
 
-        for direction in directions.iter() {
-            let mut next_pos = (pos.0, pos.1);
-
-            // Check map bounds
-            if *direction == Direction::North {
-                if pos.0 == 0 {
-                    continue;
-                }
-                next_pos.0 -= 1;
-            } else if *direction == Direction::South {
-                if pos.0 == height - 1 {
-                    continue;
-                }
-                next_pos.0 += 1;
-            } else if *direction == Direction::West {
-                if pos.1 == 0 {
-                    continue;
-                }
-                next_pos.1 -= 1;
-            } else if *direction == Direction::East {
-                if pos.1 == width - 1 {
-                    continue;
-                }
-                next_pos.1 += 1;
-            }
-
-            let next_test_node = &test_nodes[next_pos.0][next_pos.1];
-            if next_test_node.visited || map[next_pos.0][next_pos.1] == 'X' { // Skip if visited or is part of main loop
+        let neighbours: Vec<Pos> = map.neighbours4(pos.x, pos.y).map(Pos::from).collect();
+        for next_pos in neighbours {
+            if visited[next_pos] || map[next_pos] == 'X' { // Skip if visited or is part of main loop
                 continue;
             }
 
-            Self::test_node(map, test_nodes, next_pos);
+            Self::test_node(map, visited, next_pos);
         }
 
         //println!("return {:?}", pos);
@@ -271,104 +153,62 @@ impl PipeSketch {
 
     pub fn fill_reachable_tiles_ext_map(&mut self) {
         // Keep track of visited nodes
-        let mut test_nodes = Vec::new();
-        for y in 0..self.expanded_map.len() {
-            let mut line = Vec::new();
-            for x in 0..self.expanded_map.first().unwrap().len() {
-                let directions_to_test = vec![Direction::North, Direction::South, Direction::West, Direction::East];
-                line.push(TestNode {visited: false, directions_to_test: directions_to_test});
-            }
-            test_nodes.push(line);
-        }
+        let mut visited = Grid::new(self.expanded_map.width(), self.expanded_map.height(), false);
 
-        Self::test_node(&mut self.expanded_map, &mut test_nodes, (0, 0));
+        Self::test_node(&mut self.expanded_map, &mut visited, Pos::new(0, 0));
     }
 
-    pub fn print_map(map: &Vec<Vec<char>>) {
-        for line in map.iter() {
-            for c in line.iter() {
-                print!("{}", c);
-            }
-            println!()
-        }
+    pub fn print_map(map: &Grid<char>) {
+        print!("{}", map);
     }
 
     pub fn add_overlay_to_exp_map(&mut self, overlay: &VecDeque<Tile>) {
         for tile in overlay.iter() {
-            self.expanded_map[tile.pos.0][tile.pos.1] = 'X';
+            self.expanded_map[tile.pos] = 'X';
         }
     }
 
-    pub fn print_overlay(map: &Vec<Vec<char>>, overlay: &VecDeque<Tile>) {
+    pub fn print_overlay(map: &Grid<char>, overlay: &VecDeque<Tile>) {
         let mut map = map.clone();
 
         for tile in overlay.iter() {
-            map[tile.pos.0][tile.pos.1] = 'X';
+            map[tile.pos] = 'X';
         }
 
         Self::print_map(&map);
     }
 
     pub fn count_empty_spaces(&self) -> usize {
-        let mut count = 0;
-        for y in 0..self.map.len() {
-            for x in 0..self.map.first().unwrap().len() {
-                let c = self.map[y][x];
-
-                if c != 'X' && c != 'O' {
-                    count += 1;
-                }
-            }
-        }
-
-        return count;
+        return self.map.values().filter(|c| **c != 'X' && **c != 'O').count();
     }
 
     pub fn shrink_expanded_map(&mut self) {
-        for y in 0..self.expanded_map.len() {
-            for x in 0..self.expanded_map.first().unwrap().len() {
-                if y % 2 == 1 && x % 2 == 1 {
-                    self.map[y / 2][x / 2] = self.expanded_map[y][x];
-                }
-            }
+        for (x, y) in self.map.positions() {
+            self.map[y][x] = self.expanded_map[y * 2 + 1][x * 2 + 1];
         }
     }
 
     pub fn create_expanded_map(&mut self) {
-        let mut map = &mut self.expanded_map;
-
         let filler_char = '=';
 
-        // Push first line
-        let map_width = self.map.first().unwrap().len() * 2 + 1;
-        let extra_line: Vec<char> = filler_char.to_string().repeat(map_width).chars().collect();
-        map.push(extra_line.clone());
-        
-        // Push the actual content + sides
-        for line in self.map.iter() {
-            let mut line_vec = Vec::new();
-            for c in line.iter() {
-                line_vec.push(filler_char);
-                line_vec.push(*c);
-            }
-            line_vec.push(filler_char);
-
-            map.push(line_vec);
-            map.push(extra_line.clone())
+        // Surround every tile with filler tiles
+        let mut map = Grid::new(self.map.width() * 2 + 1, self.map.height() * 2 + 1, filler_char);
+        for ((x, y), c) in self.map.iter() {
+            map[y * 2 + 1][x * 2 + 1] = *c;
         }
+
+        self.expanded_map = map;
     }
 
-    pub fn parse(file: &str) -> PipeSketch {
-        let lines = aoc_helper::read_lines(file);
-        let mut map = Vec::new();
+    pub fn parse(input: &Input) -> Result<PipeSketch, ParseError> {
+        let map = Grid::parse(input, "|-LJ7F.S")?;
 
-        for line in lines {
-            let line_vec: Vec<char> = line.chars().collect();
-            map.push(line_vec);
+        if !map.values().any(|&c| c == 'S') {
+            return Err(input.end("a start tile 'S'"));
         }
 
         let start_pos = Self::find_start_pos(&map);
         
-        PipeSketch { map: map, start_pos: start_pos, expanded_map: Vec::new() }
+        Ok(PipeSketch { map: map, start_pos: start_pos, expanded_map: Grid::new(0, 0, '=') })
     }
 }
\ No newline at end of file
@@ -0,0 +1,39 @@
+pub mod task1;
+pub mod task2;
+pub mod utils;
+
+use aoc_helper::{Answer, Input, ParseError, Solver};
+
+use crate::utils::SkyImage;
+
+pub struct Day11 {
+    sky_image: SkyImage
+}
+
+impl Solver for Day11 {
+    fn parse_input(input: &Input) -> Result<Self, ParseError> {
+        return Ok(Day11 { sky_image: SkyImage::parse(input)? });
+    }
+
+    fn part1(&self) -> Answer {
+        return task1::solve(&self.sky_image).into();
+    }
+
+    fn part2(&self) -> Answer {
+        return task2::solve(&self.sky_image).into();
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE_INPUT: &str = "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+";
@@ -1,12 +1,17 @@
+use aoc_helper::Solver;
+
+use crate::Day11;
 use crate::utils::SkyImage;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_example_input() {
-        let sky_image = SkyImage::parse("../input/11_1_example_input.txt");
+        let sky_image = SkyImage::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
         let shortest_paths_1 = sky_image.find_shortest_paths(10);
         let sum_1 = shortest_paths_1.iter().map(|p| p.1).sum::<usize>();
 
@@ -19,10 +24,14 @@ mod tests {
     }
 }
 
-pub fn run_task() {
-    let sky_image = SkyImage::parse("input/11_1_input.txt");
+pub fn solve(sky_image: &SkyImage) -> usize {
     let shortest_paths = sky_image.find_shortest_paths(1000000);
-    let sum = shortest_paths.iter().map(|p| p.1).sum::<usize>();
 
-    println!("Sum is {}", sum);
-}
\ No newline at end of file
+    return shortest_paths.iter().map(|p| p.1).sum::<usize>();
+}
+
+pub fn run_task(file: &str) {
+    let day = Day11::parse_file(file).unwrap();
+
+    println!("Sum is {}", day.part2());
+}
@@ -1,5 +1,8 @@
 use std::collections::HashMap;
 
+use aoc_helper::{Input, ParseError};
+use aoc_helper::grid::Grid;
+
 #[derive(PartialEq, Eq, Hash, Clone, Copy)]
 pub struct Point {
     y: usize,
@@ -7,7 +10,7 @@ pub struct Point {
 }
 
 pub struct SkyImage {
-    map: Vec<Vec<char>>
+    map: Grid<char>
 }
 
 static GALAXY: char = '#';
@@ -40,25 +43,25 @@ impl SkyImage {
     // }
 
     fn is_empty_row(&self, row: usize) -> bool {
-        return self.map[row].iter().all(|c| *c == EMPTY)
+        return self.map.row(row).iter().all(|c| *c == EMPTY)
     }
 
     fn is_empty_col(&self, col: usize) -> bool {
-        return self.map.iter().map(|row| row[col]).all(|c| c == EMPTY)
+        return self.map.column(col).all(|c| *c == EMPTY)
     }
 
     fn find_galaxies(&self, expansion_factor: usize) -> Vec<Point> {
         let mut galaxies= Vec::new();
 
         let mut empty_rows = 0;
-        for row in 0..self.map.len() {
+        for row in 0..self.map.height() {
             if self.is_empty_row(row) {
                 empty_rows += 1;
                 continue;
             }
 
             let mut empty_cols = 0;
-            for col in 0..self.map.first().unwrap().len() {
+            for col in 0..self.map.width() {
                 if self.is_empty_col(col) {
                     empty_cols += 1;
                     continue;
@@ -94,25 +97,14 @@ impl SkyImage {
     }
 
     pub fn print(&self) {
-        for line in self.map.iter() {
-            for c in line {
-                print!("{}", c);
-            }
-            println!();
-        }
+        print!("{}", self.map);
     }
 
-    pub fn parse(file: &str) -> SkyImage {
-        let lines = aoc_helper::read_lines(file);
-        let mut map = Vec::new();
-
-        for line in lines {
-            let line_vec: Vec<char> = line.chars().collect();
-            map.push(line_vec);
-        }
+    pub fn parse(input: &Input) -> Result<SkyImage, ParseError> {
+        let map = Grid::parse(input, ".#")?;
 
         //Self::expand_universe(&mut map);
 
-        SkyImage { map: map }
+        Ok(SkyImage { map: map })
     }
 }
\ No newline at end of file
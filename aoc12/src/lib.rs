@@ -0,0 +1,61 @@
+pub mod task1;
+pub mod task2;
+pub mod utils;
+
+use aoc_helper::{Answer, Input, ParseError, Solver};
+
+use crate::utils::SpringMap;
+#[cfg(test)]
+use aoc_helper::testing::Lcg;
+
+pub struct Day12 {
+    spring_map: SpringMap
+}
+
+impl Solver for Day12 {
+    fn parse_input(input: &Input) -> Result<Self, ParseError> {
+        return Ok(Day12 { spring_map: SpringMap::parse(input)? });
+    }
+
+    fn part1(&self) -> Answer {
+        return task1::solve(&self.spring_map).into();
+    }
+
+    fn part2(&self) -> Answer {
+        return task2::solve(self.spring_map.clone()).into();
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE_INPUT: &str = "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+";
+
+// Springs with random rows, made from a random arrangement so they have at least one
+#[cfg(test)]
+fn random_springs(rng: &mut Lcg, count: usize, max_len: u64) -> String {
+    let mut random = |n: u64| rng.below(n);
+
+    let mut springs = String::new();
+    while springs.lines().count() < count {
+        let arrangement: Vec<bool> = (0..1 + random(max_len)).map(|_| random(2) == 0).collect();
+        let groups: Vec<String> = arrangement.split(|damaged| !damaged).filter(|group| !group.is_empty()).map(|group| group.len().to_string()).collect();
+        if groups.is_empty() {
+            continue;
+        }
+
+        let row: String = arrangement.iter().map(|damaged| match (random(3), damaged) {
+            (0, _) => '?',
+            (_, true) => '#',
+            (_, false) => '.'
+        }).collect();
+        springs += &format!("{} {}\n", row, groups.join(","));
+    }
+
+    return springs;
+}
@@ -1,3 +1,6 @@
+use aoc_helper::Solver;
+
+use crate::Day12;
 use crate::utils::SpringMap;
 
 #[cfg(test)]
@@ -5,10 +8,13 @@ mod tests {
     use crate::utils::SpringMap;
 
     use super::*;
+    use aoc_helper::Input;
+    use aoc_helper::testing::Lcg;
+    use crate::{random_springs, EXAMPLE_INPUT};
 
     #[test]
     fn test_example_input() {
-        let spring_map = SpringMap::parse("../input/12_1_example_input.txt");
+        let spring_map = SpringMap::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
         //spring_map.print();
 
         let arrangements = spring_map.calculate_arrangements();
@@ -16,11 +22,24 @@ mod tests {
         
         let sum = arrangements.iter().sum::<usize>();
         assert_eq!(sum, 21);
+
+        assert_eq!(spring_map.count_arrangements(), vec![1, 4, 1, 1, 4, 10]);
+    }
+
+    #[test]
+    fn test_count_against_brute_force() {
+        let mut rng = Lcg::new(12);
+        let spring_map = SpringMap::parse(&Input::new(&random_springs(&mut rng, 300, 14))).unwrap();
+
+        let expected: Vec<u64> = spring_map.calculate_arrangements().iter().map(|count| *count as u64).collect();
+        assert_eq!(spring_map.count_arrangements(), expected);
     }
     
     #[test]
+    #[ignore = "needs the puzzle input input/12_1_input_filtered_3.txt, which is not in the repo"]
     fn test_input_filtered_3() {
-        let spring_map = SpringMap::parse("../input/12_1_input_filtered_3.txt");
+        let input = Input::read(concat!(env!("CARGO_MANIFEST_DIR"), "/../input/12_1_input_filtered_3.txt")).unwrap();
+        let spring_map = SpringMap::parse(&input).unwrap();
 
         let arrangements = spring_map.calculate_arrangements();
         
@@ -29,9 +48,12 @@ mod tests {
     }
 }
 
-pub fn run_task() {
-    let spring_map = SpringMap::parse("input/12_1_input.txt");
-    let arrangements = spring_map.calculate_arrangements();
-    let sum = arrangements.iter().sum::<usize>();
-    println!("Sum is {}", sum);
-}
\ No newline at end of file
+pub fn solve(spring_map: &SpringMap) -> u64 {
+    return spring_map.count_arrangements().iter().sum();
+}
+
+pub fn run_task(file: &str) {
+    let day = Day12::parse_file(file).unwrap();
+
+    println!("Sum is {}", day.part1());
+}
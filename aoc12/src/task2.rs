@@ -1,3 +1,6 @@
+use aoc_helper::Solver;
+
+use crate::Day12;
 use crate::utils::SpringMap;
 
 #[cfg(test)]
@@ -5,10 +8,17 @@ mod tests {
     use crate::utils::SpringMap;
 
     use super::*;
+    use aoc_helper::Input;
+    use aoc_helper::testing::Lcg;
+    use crate::{random_springs, EXAMPLE_INPUT};
+
+    const EXAMPLE_INPUT_EXTRA: &str = "\
+?###???????? 3,2,1
+";
 
     #[test]
     fn test_extra_input() {
-        let mut spring_map = SpringMap::parse("../input/12_1_example_input_extra.txt");
+        let mut spring_map = SpringMap::parse(&Input::new(EXAMPLE_INPUT_EXTRA)).unwrap();
         spring_map.unfold();
 
         let arrangements = spring_map.calculate_arrangements_smart();
@@ -20,7 +30,7 @@ mod tests {
 
     #[test]
     fn test_example_input() {
-        let mut spring_map = SpringMap::parse("../input/12_1_example_input.txt");
+        let mut spring_map = SpringMap::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
         spring_map.unfold();
 
         let arrangements = spring_map.calculate_arrangements_smart_mt();
@@ -28,11 +38,38 @@ mod tests {
         
         let sum = arrangements.iter().sum::<usize>();
         assert_eq!(sum, 525152);
+
+        assert_eq!(spring_map.count_arrangements(), vec![1, 16384, 1, 16, 2500, 506250]);
     }
 
     #[test]
+    fn test_count_against_smart() {
+        let mut rng = Lcg::new(25);
+        let mut spring_map = SpringMap::parse(&Input::new(&random_springs(&mut rng, 100, 8))).unwrap();
+        spring_map.unfold();
+
+        let expected: Vec<u64> = spring_map.calculate_arrangements_smart().iter().map(|count| *count as u64).collect();
+        assert_eq!(spring_map.count_arrangements(), expected);
+    }
+
+    #[test]
+    fn test_count_large() {
+        // Every way to place 15 single springs in 99 positions, far too many to enumerate
+        let mut spring_map = SpringMap::parse(&Input::new("??????????????????? 1,1,1\n")).unwrap();
+        spring_map.unfold();
+
+        assert_eq!(spring_map.count_arrangements(), vec![binomial(99 - 15 + 1, 15)]);
+    }
+
+    fn binomial(n: u64, k: u64) -> u64 {
+        return (0..k).fold(1, |res, i| res * (n - i) / (i + 1));
+    }
+
+    #[test]
+    #[ignore = "needs the puzzle input input/12_1_input_filtered.txt, which is not in the repo"]
     fn test_input_filtered() {
-        let mut spring_map = SpringMap::parse("../input/12_1_input_filtered.txt");
+        let input = Input::read(concat!(env!("CARGO_MANIFEST_DIR"), "/../input/12_1_input_filtered.txt")).unwrap();
+        let mut spring_map = SpringMap::parse(&input).unwrap();
         spring_map.unfold();
 
         let arrangements = spring_map.calculate_arrangements_smart_mt();
@@ -42,51 +79,40 @@ mod tests {
     }
 
     #[test]
+    #[ignore = "needs the puzzle input input/12_1_input_filtered_2.txt, which is not in the repo"]
     fn test_input_filtered_2() {
-        let mut spring_map = SpringMap::parse("../input/12_1_input_filtered_2.txt");
+        let input = Input::read(concat!(env!("CARGO_MANIFEST_DIR"), "/../input/12_1_input_filtered_2.txt")).unwrap();
+        let mut spring_map = SpringMap::parse(&input).unwrap();
         spring_map.unfold();
 
         let arrangements = spring_map.calculate_arrangements_smart_mt();
         
         let sum = arrangements.iter().sum::<usize>();
         assert_eq!(sum, 49762);
-
-        // 7776
-        // 49762 - 1
-
-        // Without unfold()
-        // 6
-        // 6
-
-        // With 2x fold
-        // 36
-        // 50
-
-
-
     }
 
     #[test]
+    #[ignore = "needs the puzzle input input/12_1_input_filtered_3.txt, which is not in the repo"]
     fn test_input_filtered_3() {
-        let mut spring_map = SpringMap::parse("../input/12_1_input_filtered_3.txt");
+        let input = Input::read(concat!(env!("CARGO_MANIFEST_DIR"), "/../input/12_1_input_filtered_3.txt")).unwrap();
+        let mut spring_map = SpringMap::parse(&input).unwrap();
         spring_map.unfold();
 
         let arrangements = spring_map.calculate_arrangements_smart_mt();
         
         let sum = arrangements.iter().sum::<usize>();
         assert_eq!(sum, 49762);
-
-        // 191
-        // 190
     }
 }
 
-pub fn run_task() {
-    let mut spring_map = SpringMap::parse("input/12_1_input.txt");
+pub fn solve(mut spring_map: SpringMap) -> u64 {
     spring_map.unfold();
 
-    let arrangements = spring_map.calculate_arrangements_smart_mt();
+    return spring_map.count_arrangements().iter().sum();
+}
+
+pub fn run_task(file: &str) {
+    let day = Day12::parse_file(file).unwrap();
 
-    let sum = arrangements.iter().sum::<usize>();
-    println!("Sum is {}", sum);
-}
\ No newline at end of file
+    println!("Sum is {}", day.part2());
+}
@@ -1,7 +1,9 @@
-use std::collections::{VecDeque, HashMap};
-use chrono;
-use std::sync::{Arc, Mutex};
-use std::thread;
+use std::collections::HashMap;
+#[cfg(test)]
+use std::collections::VecDeque;
+
+use aoc_helper::{Input, Line, ParseError};
+use aoc_helper::parallel::Executor;
 
 #[derive(Debug, Clone)]
 struct Spring {
@@ -9,17 +11,11 @@ struct Spring {
     group_sizes: Vec<usize>
 }
 
+#[derive(Clone)]
 pub struct SpringMap {
     springs: Vec<Spring>
 }
 
-pub struct MtContext {
-    arrangements: HashMap<usize, usize>,
-    springs: Vec<Spring>,
-    in_progress: Vec<usize>,
-    i: usize
-}
-
 impl Spring {
     fn unfold(&mut self) {
         let mut new_row = Vec::new();
@@ -38,6 +34,7 @@ impl Spring {
         self.group_sizes = new_group_sizes;
     }
 
+    #[cfg(test)]
     fn is_valid(row: Vec<char>, group_sizes: &Vec<usize>) -> bool {
         let s: String = row.iter().collect();
         let splits: Vec<&str> = s.split('.').collect();
@@ -136,6 +133,7 @@ impl Spring {
     //     return Self::calculate_arrangements_rec(&self.row, &mut self.row.clone(), self.row.len(), 0, &mut queue);
     // }
 
+    #[cfg(test)]
     fn calculate_arrangements_rec(orig_row: &Vec<char>, row: &mut Vec<char>, row_len: usize, start_i: usize, queue: &mut VecDeque<usize>) -> Vec<(usize, usize)> {      
         let group_size = queue.pop_front().unwrap();
         let group_sum_plus_dots = queue.iter().sum::<usize>() + group_size + queue.len();
@@ -224,6 +222,7 @@ impl Spring {
         return res;
     }
 
+    #[cfg(test)]
     fn calculate_arrangements_smart(&self) -> usize {
         let mut queue = VecDeque::new();
         self.group_sizes.iter().for_each(|group_size| queue.push_back(*group_size));
@@ -233,11 +232,46 @@ impl Spring {
         return res.into_iter().map(|tmp| tmp.1).sum();
     }
 
-    fn print_row(row: &Vec<char>) {
-        row.iter().for_each(|c| print!("{}", c));
-        println!()
+    /// Count the arrangements with dynamic programming, in `O(row length * groups * largest group)`
+    fn count_arrangements(&self) -> u64 {
+        return self.count_arrangements_from(0, 0, 0, &mut HashMap::new());
     }
 
+    // Arrangements of the rest of the row, with `group` groups completed before `pos` and a run of `run` '#'s ending there
+    fn count_arrangements_from(&self, pos: usize, group: usize, run: usize, memo: &mut HashMap<(usize, usize, usize), u64>) -> u64 {
+        let group_count = self.group_sizes.len();
+
+        if pos == self.row.len() {
+            let done = (group == group_count && run == 0) || (group + 1 == group_count && run == self.group_sizes[group]);
+            return if done { 1 } else { 0 };
+        }
+
+        if let Some(count) = memo.get(&(pos, group, run)) {
+            return *count;
+        }
+
+        let mut count = 0;
+
+        // Extend the current run, if the group it belongs to is not full yet
+        if self.row[pos] != '.' && group < group_count && run < self.group_sizes[group] {
+            count += self.count_arrangements_from(pos + 1, group, run + 1, memo);
+        }
+
+        // End the current run (if any), which has to complete its group exactly
+        if self.row[pos] != '#' {
+            if run == 0 {
+                count += self.count_arrangements_from(pos + 1, group, 0, memo);
+            } else if run == self.group_sizes[group] {
+                count += self.count_arrangements_from(pos + 1, group + 1, 0, memo);
+            }
+        }
+
+        memo.insert((pos, group, run), count);
+
+        return count;
+    }
+
+    #[cfg(test)]
     fn calculate_arrangements(&self) -> usize {
         //println!("Calculating for row {:?}", self.row);
 
@@ -277,12 +311,19 @@ impl Spring {
         return res;
     }
 
-    fn parse(line: &str) -> Spring {
-        let mut split_line = line.split(" ");
-        let row = split_line.next().unwrap().chars().collect();
-        let group_sizes: Vec<usize> = split_line.next().unwrap().split(",").map(|s| s.parse::<usize>().unwrap()).collect();
+    fn parse(line: &Line) -> Result<Spring, ParseError> {
+        let mut split_line = line.text.split(" ");
+        let row_str = split_line.next().unwrap();
+
+        if let Some(i) = row_str.find(|c| !"?.#".contains(c)) {
+            return Err(line.error(&row_str[i..], "'?', '.' or '#'"));
+        }
+
+        let row = row_str.chars().collect();
+        let group_sizes_str = split_line.next().ok_or_else(|| line.end("group sizes"))?;
+        let group_sizes: Vec<usize> = group_sizes_str.split(",").map(|s| line.parse::<usize>(s, "a group size")).collect::<Result<_, _>>()?;
         
-        Spring { row: row, group_sizes: group_sizes }
+        Ok(Spring { row: row, group_sizes: group_sizes })
     }
 }
 
@@ -293,81 +334,25 @@ impl SpringMap {
         }
     }
 
+    /// Arrangements of every spring
+    pub fn count_arrangements(&self) -> Vec<u64> {
+        return Executor::default().map(self.springs.iter().collect(), |spring| spring.count_arrangements());
+    }
+
+    // Note: `calculate_arrangements_smart(_mt)` and `calculate_arrangements` are slower ways to get the same counts,
+    // only kept for the tests to check `count_arrangements`
+    #[cfg(test)]
     pub fn calculate_arrangements_smart(&self) -> Vec<usize> {
-        let mut i = 0;
-        self.springs.iter().map(|spring| {
-            i += 1;
-            println!("{:?}", chrono::offset::Local::now());
-            println!("Working on spring {}/{}...", i, self.springs.len());
-            spring.calculate_arrangements_smart()
-        }).collect()
+        self.springs.iter().map(|spring| spring.calculate_arrangements_smart()).collect()
     }
 
+    /// [`SpringMap::calculate_arrangements_smart`] with the springs spread over threads
+    #[cfg(test)]
     pub fn calculate_arrangements_smart_mt(&self) -> Vec<usize> {
-        let mut cloned_springs = self.springs.clone();
-        cloned_springs.reverse();
-        let tmp_ctx = MtContext { arrangements: HashMap::new(), springs: cloned_springs, i: 0, in_progress: Vec::new() };
-        let ctx = Arc::new(Mutex::new(tmp_ctx));
-
-        let mut handles = vec![];
-
-        // 14
-        for _ in 0..1 {
-            let lctx = Arc::clone(&ctx);
-            let handle = thread::spawn(move || {
-                loop {
-                    let spring: Spring;
-                    let i: usize;
-
-                    {
-                        let mut tmp_lctx = lctx.lock().unwrap();
-
-                        if tmp_lctx.springs.is_empty() {
-                            println!("Queue empty, exiting thread");
-                            return;
-                        }
-                        
-                        spring = tmp_lctx.springs.pop().unwrap();
-                        tmp_lctx.i += 1;
-                        i = tmp_lctx.i;
-                        //tmp_lctx.in_progress.push(i);
-                    }
-
-                    let arrangements = spring.calculate_arrangements_smart();
-
-                    {
-                        let mut tmp_lctx = lctx.lock().unwrap();
-
-                        tmp_lctx.arrangements.insert(i, arrangements);
-
-                        println!("{:?}: Completed spring: {}", chrono::offset::Local::now(), i);
-
-                        // let position = tmp_lctx.in_progress.iter().position(|element| *element == i).unwrap();
-                        // tmp_lctx.in_progress.remove(position);
-
-                        // if tmp_lctx.springs.is_empty() {
-                        //     println!("In progress: {:?}", tmp_lctx.in_progress);
-                        // }
-                    }
-                }
-            });
-            handles.push(handle);
-        }
-
-        for handle in handles {
-            handle.join().unwrap();
-        }
-
-        // Convert HashMap result into Vec
-        let arrangements = &ctx.lock().unwrap().arrangements;
-        let mut res = Vec::new();
-        for i in 0..arrangements.len() {
-            res.push(*arrangements.get(&(i + 1)).unwrap());
-        }
-
-        return res;
+        return Executor::default().map(self.springs.iter().collect(), |spring| spring.calculate_arrangements_smart());
     }
 
+    #[cfg(test)]
     pub fn calculate_arrangements(&self) -> Vec<usize> {
         self.springs.iter().map(|spring| {
             //println!("Working on new spring...");
@@ -380,10 +365,9 @@ impl SpringMap {
         self.springs.iter_mut().for_each(|spring| spring.unfold());
     }
 
-    pub fn parse(file: &str) -> SpringMap {
-        let lines = aoc_helper::read_lines(file);
-        let springs = lines.iter().map(|line| Spring::parse(line)).collect();
+    pub fn parse(input: &Input) -> Result<SpringMap, ParseError> {
+        let springs = input.lines().iter().map(|line| Spring::parse(line)).collect::<Result<_, _>>()?;
         
-        SpringMap { springs: springs }
+        Ok(SpringMap { springs: springs })
     }
 }
\ No newline at end of file
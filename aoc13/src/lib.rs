@@ -0,0 +1,44 @@
+pub mod task1;
+pub mod task2;
+pub mod utils;
+
+use aoc_helper::{Answer, Input, ParseError, Solver};
+
+use crate::utils::PatternNotes;
+
+pub struct Day13 {
+    pattern_notes: PatternNotes
+}
+
+impl Solver for Day13 {
+    fn parse_input(input: &Input) -> Result<Self, ParseError> {
+        return Ok(Day13 { pattern_notes: PatternNotes::parse(input)? });
+    }
+
+    fn part1(&self) -> Answer {
+        return task1::solve(&self.pattern_notes).into();
+    }
+
+    fn part2(&self) -> Answer {
+        return task2::solve(self.pattern_notes.clone()).into();
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE_INPUT: &str = "\
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#
+";
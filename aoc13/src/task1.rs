@@ -1,22 +1,41 @@
+use aoc_helper::Solver;
+
+use crate::Day13;
 use crate::utils::PatternNotes;
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_example_input() {
-        let pattern_notes = PatternNotes::parse("../input/13_1_example_input.txt");
+        let pattern_notes = PatternNotes::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
         let sum = pattern_notes.summarize_notes();
 
         assert_eq!(sum, 405);
     }
+
+    #[test]
+    fn test_last_rows() {
+        // The first pattern only reflects with its last row, the second one is a single row
+        let pattern_notes = PatternNotes::parse(&Input::new("#.\n.#\n.#\n\n#..#\n\n")).unwrap();
+        assert_eq!(pattern_notes.summarize_notes(), 200 + 2);
+
+        let error = PatternNotes::parse(&Input::new("#.\n\n\n#.\n")).err().unwrap();
+        assert_eq!(error.to_string(), "<input>:3:1: expected a row of '.' and '#', found \"\"");
+        assert!(PatternNotes::parse(&Input::new("\n")).is_err());
+    }
+}
+
+pub fn solve(pattern_notes: &PatternNotes) -> usize {
+    return pattern_notes.summarize_notes();
 }
 
-pub fn run_task() {
-    let pattern_notes = PatternNotes::parse("input/13_1_input.txt");
-    let sum = pattern_notes.summarize_notes();
+pub fn run_task(file: &str) {
+    let day = Day13::parse_file(file).unwrap();
 
-    println!("Sum is {}", sum);
-}
\ No newline at end of file
+    println!("Sum is {}", day.part1());
+}
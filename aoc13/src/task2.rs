@@ -1,21 +1,29 @@
+use aoc_helper::Solver;
+
+use crate::Day13;
 use crate::utils::PatternNotes;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_example_input() {
-        let mut pattern_notes = PatternNotes::parse("../input/13_1_example_input.txt");
+        let mut pattern_notes = PatternNotes::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
         let sum = pattern_notes.fix_smudges_and_summarize();
 
         assert_eq!(sum, 400);
     }
 }
 
-pub fn run_task() {
-    let mut pattern_notes = PatternNotes::parse("input/13_1_input.txt");
-    let sum = pattern_notes.fix_smudges_and_summarize();
+pub fn solve(mut pattern_notes: PatternNotes) -> usize {
+    return pattern_notes.fix_smudges_and_summarize();
+}
+
+pub fn run_task(file: &str) {
+    let day = Day13::parse_file(file).unwrap();
 
-    println!("Sum is {}", sum);
-}
\ No newline at end of file
+    println!("Sum is {}", day.part2());
+}
@@ -1,13 +1,18 @@
+use aoc_helper::{Input, Line, ParseError};
+use aoc_helper::grid::Grid;
+
 #[derive(PartialEq)]
 struct LineOfReflection {
     horizontal: bool,
     coordinates: (usize, usize)
 }
 
+#[derive(Clone)]
 struct Pattern {
-    rows: Vec<Vec<char>>
+    rows: Grid<char>
 }
 
+#[derive(Clone)]
 pub struct PatternNotes {
     patterns: Vec<Pattern>
 }
@@ -30,30 +35,14 @@ impl Pattern {
     }
 
     fn get_horizontal_hashes(&self) -> Vec<u64> {
-        let mut hashes = Vec::new();
-        let rows = self.rows.len();
-
-        for y in 0..rows {
-            let str: String = self.rows[y].iter().collect();
-            hashes.push(aoc_helper::hash(str));
-        }
-
-        return hashes;
+        return self.rows.rows().map(|row| aoc_helper::hash(row.iter().collect::<String>())).collect();
     }
 
     fn get_vertical_hashes(&self) -> Vec<u64> {
-        let mut hashes = Vec::new();
-        let cols = self.rows.first().unwrap().len();
-
-        for x in 0..cols {
-            let str: String = self.rows.iter().map(|s| s[x]).collect();
-            hashes.push(aoc_helper::hash(str));
-        }
-
-        return hashes;
+        return self.rows.columns().map(|column| aoc_helper::hash(column.collect::<String>())).collect();
     }
 
-    fn flip_char(rows: &mut Vec<Vec<char>>, y: usize, x: usize) {
+    fn flip_char(rows: &mut Grid<char>, y: usize, x: usize) {
         if rows[y][x] == '.' {
             rows[y][x] = '#';
         } else {
@@ -69,7 +58,7 @@ impl Pattern {
         }
         let baseline_reflection = baseline_reflections.remove(0);
 
-        let dimensions = (self.rows.len(), self.rows.first().unwrap().len());
+        let dimensions = (self.rows.height(), self.rows.width());
         for y in 0..dimensions.0 {
             for x in 0..dimensions.1 {
                 // Change . to # and vice versa
@@ -130,10 +119,10 @@ impl Pattern {
         return lines;
     }
 
-    fn parse(lines: Vec<&String>) -> Pattern {
-        let rows = lines.iter().map(|line| line.chars().collect()).collect();
+    fn parse(lines: Vec<Line>) -> Result<Pattern, ParseError> {
+        let rows = Grid::parse_lines(&lines, ".#")?;
 
-        Pattern { rows: rows }
+        Ok(Pattern { rows: rows })
     }
 }
 
@@ -172,21 +161,35 @@ impl PatternNotes {
         Self::summarize(&line_of_reflections)
     }
 
-    pub fn parse(file: &str) -> PatternNotes {
-        let lines = aoc_helper::read_lines(file);
-        let mut it = lines.iter().peekable();
+    pub fn parse(input: &Input) -> Result<PatternNotes, ParseError> {
+        let mut lines = input.lines();
+        // Note: Empty lines at the end of the input don't separate patterns
+        while lines.last().is_some_and(|line| line.text.is_empty()) {
+            lines.pop();
+        }
         let mut grouped_lines = Vec::new();
 
         let mut patterns = Vec::new();
-        while let Some(line) = it.next() {
-            if line.is_empty() || it.peek().is_none() {
-                patterns.push(Pattern::parse(grouped_lines));
-                grouped_lines = Vec::new();
-            } else {
-                grouped_lines.push(line);
+        for line in lines.iter() {
+            if !line.text.is_empty() {
+                grouped_lines.push(*line);
+                continue;
+            }
+
+            // An empty line ends a pattern, which needs at least one row
+            if grouped_lines.is_empty() {
+                return Err(line.error(line.text, "a row of '.' and '#'"));
             }
+            patterns.push(Pattern::parse(grouped_lines)?);
+            grouped_lines = Vec::new();
+        }
+
+        // The last pattern ends with the input
+        if grouped_lines.is_empty() {
+            return Err(input.end("a row of '.' and '#'"));
         }
+        patterns.push(Pattern::parse(grouped_lines)?);
 
-        PatternNotes { patterns: patterns }
+        Ok(PatternNotes { patterns: patterns })
     }
 }
\ No newline at end of file
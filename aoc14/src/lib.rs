@@ -0,0 +1,39 @@
+pub mod task1;
+pub mod task2;
+pub mod utils;
+
+use aoc_helper::{Answer, Input, ParseError, Solver};
+
+use crate::utils::RockMap;
+
+pub struct Day14 {
+    rock_map: RockMap
+}
+
+impl Solver for Day14 {
+    fn parse_input(input: &Input) -> Result<Self, ParseError> {
+        return Ok(Day14 { rock_map: RockMap::parse(input)? });
+    }
+
+    fn part1(&self) -> Answer {
+        return task1::solve(self.rock_map.clone()).into();
+    }
+
+    fn part2(&self) -> Answer {
+        return task2::solve(self.rock_map.clone()).into();
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE_INPUT: &str = "\
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+";
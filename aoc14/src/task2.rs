@@ -1,13 +1,18 @@
+use aoc_helper::Solver;
+
+use crate::Day14;
 use crate::utils::RockMap;
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_example_input() {
-        let mut rock_map = RockMap::parse("../input/14_1_example_input.txt");
+        let mut rock_map = RockMap::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
         
         rock_map.spin_n_cycles(1000000000);
 
@@ -17,12 +22,14 @@ mod tests {
     }
 }
 
-pub fn run_task() {
-    let mut rock_map = RockMap::parse("input/14_1_input.txt");
-        
-        rock_map.spin_n_cycles(1000000000);
+pub fn solve(mut rock_map: RockMap) -> u64 {
+    rock_map.spin_n_cycles(1000000000);
 
-        let north_load = rock_map.calculate_north_load();
+    return rock_map.calculate_north_load();
+}
+
+pub fn run_task(file: &str) {
+    let day = Day14::parse_file(file).unwrap();
 
-        println!("North load: {}", north_load);
-}
\ No newline at end of file
+    println!("North load: {}", day.part2());
+}
@@ -1,16 +1,10 @@
-use std::collections::HashMap;
-
-#[derive(PartialEq)]
-#[derive(Copy, Clone)]
-enum Direction {
-    NORTH,
-    WEST,
-    SOUTH,
-    EAST
-}
+use aoc_helper::{cycle, Input, ParseError};
+use aoc_helper::geom::Direction;
+use aoc_helper::grid::Grid;
 
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct RockMap {
-    map: Vec<Vec<char>>
+    map: Grid<char>
 }
 
 static ROUNDED_ROCK: char = 'O';
@@ -19,69 +13,45 @@ static EMPTY: char = '.';
 
 impl RockMap {
     pub fn print(&self) {
-        self.map.iter().for_each(|line| println!("{:?}", line));
+        self.map.rows().for_each(|line| println!("{:?}", line));
     }
 
     pub fn calculate_north_load(&self) -> u64 {
         let mut score = 0;
-        for (i, line_vec) in self.map.iter().enumerate() {
-            let row_single_rock_load = (self.map.len() - i) as u64;
+        for (i, line_vec) in self.map.rows().enumerate() {
+            let row_single_rock_load = (self.map.height() - i) as u64;
             score += (line_vec.iter().filter(|c| **c == ROUNDED_ROCK).count() as u64) * row_single_rock_load;
         }
 
         return score;
     }
 
+    // Rotate the map so that `direction` points north
     fn transform(&mut self, direction: &Direction) {
-        let width = self.map.first().unwrap().len();
-
-        if *direction == Direction::NORTH {
-            // Do nothing?
-        } else if *direction == Direction::WEST {
-            let mut new_map = Vec::new();
-            for x in 0..width {
-                let new_row: Vec<char> = self.map.iter_mut().map(|row| row[x]).rev().collect();
-                new_map.push(new_row);
-            }
-            self.map = new_map;
-        } else if *direction == Direction::SOUTH {
-            let height = self.map.len();
-
-            for x in 0..width {
-                let tmp_col: Vec<char> = self.map.iter().map(|line| line[x]).rev().collect();
-                
-                for (y, c) in tmp_col.into_iter().enumerate() {
-                    self.map[y][x] = c;
-                }
-            }
-        }  else if *direction == Direction::EAST {
-            let mut new_map = Vec::new();
-            for x in (0..width).rev() {
-                let new_row: Vec<char> = self.map.iter_mut().map(|row| row[x]).collect();
-                new_map.push(new_row);
-            }
-            self.map = new_map;
-        } else {
-            panic!("Unknown transform direction");
+        match direction {
+            Direction::North => (),
+            Direction::West => self.map = self.map.rotate_clockwise(),
+            Direction::South => self.map = self.map.rotate_clockwise().rotate_clockwise(),
+            Direction::East => self.map = self.map.rotate_counterclockwise()
         }
     }
 
     fn tilt(&mut self, direction: Direction) {
-        let mut tmp_direction = direction;
-        self.transform(&tmp_direction);
+        self.transform(&direction);
         self.tilt_north();
-        if tmp_direction == Direction::WEST {
-            tmp_direction = Direction::EAST;
-        } else if tmp_direction == Direction::EAST {
-            tmp_direction = Direction::WEST;
+
+        // Rotate back, turning north or south twice is its own inverse
+        if direction.is_vertical() {
+            self.transform(&direction);
+        } else {
+            self.transform(&direction.reverse());
         }
-        self.transform(&tmp_direction);
     }
 
     pub fn tilt_north(&mut self) {
-        let width = self.map.first().unwrap().len();
+        let width = self.map.width();
 
-        for y in 0..self.map.len() {
+        for y in 0..self.map.height() {
             for x in 0..width {
                 let c = self.map[y][x];
 
@@ -89,7 +59,7 @@ impl RockMap {
                     continue;
                 }
 
-                let tmp_part_col: Vec<char> = self.map[0..y].iter().map(|line| line[x]).collect();
+                let tmp_part_col: Vec<char> = self.map.column(x).take(y).copied().collect();
                 let mut new_row = 0;
                 for i in (0..tmp_part_col.len()).rev() {
                     if tmp_part_col[i] != EMPTY {
@@ -105,53 +75,24 @@ impl RockMap {
     }
 
     pub fn spin_cycle(&mut self) {
-        self.tilt(Direction::NORTH);
-        self.tilt(Direction::WEST);
-        self.tilt(Direction::SOUTH);
-        self.tilt(Direction::EAST);
-    }
-
-    fn find_cycle(&mut self, n: u32) -> (u32, u32) {
-        let mut hm = HashMap::new();
-
-        for i in 0..n {
-            self.spin_cycle();
-
-            let hash = aoc_helper::hash_ref(&self.map);
-            if hm.contains_key(&hash) {
-                return (i, *hm.get(&hash).unwrap());
-            } else {
-                hm.insert(hash, i);
-            }
-        }
-
-        panic!("Found no cycle!");
+        self.tilt(Direction::North);
+        self.tilt(Direction::West);
+        self.tilt(Direction::South);
+        self.tilt(Direction::East);
     }
 
+    /// Run `n` spin cycles, skipping ahead once the rocks are back in an earlier position
     pub fn spin_n_cycles(&mut self, n: u32) {
-        let cycle = self.find_cycle(n);
-        println!("Cycle: {:?}", cycle);
-
-        let cycle_len = cycle.0 - cycle.1;
-
-        let n_remaining = n - (cycle.0 + 1);
-        let cycles_mod = n_remaining % cycle_len;
-
-        for _ in 0..cycles_mod {
-            self.spin_cycle();
-        }
+        *self = cycle::state_at(self.clone(), |rock_map| {
+            let mut next = rock_map.clone();
+            next.spin_cycle();
+            next
+        }, n as usize);
     }
 
-    pub fn parse(file: &str) -> RockMap {
-        let lines = aoc_helper::read_lines(file);
-        let mut map = Vec::new();
-
-        for line in lines {
-            let line_vec: Vec<char> = line.chars().collect();
-
-            map.push(line_vec);
-        }
+    pub fn parse(input: &Input) -> Result<RockMap, ParseError> {
+        let map = Grid::parse(input, "O#.")?;
 
-        RockMap { map: map }
+        Ok(RockMap { map: map })
     }
 }
\ No newline at end of file
@@ -0,0 +1,30 @@
+pub mod task1;
+pub mod task2;
+pub mod utils;
+
+use aoc_helper::{Answer, Input, ParseError, Solver};
+
+use crate::utils::InitSequence;
+
+pub struct Day15 {
+    init_seq: InitSequence
+}
+
+impl Solver for Day15 {
+    fn parse_input(input: &Input) -> Result<Self, ParseError> {
+        return Ok(Day15 { init_seq: InitSequence::parse(input)? });
+    }
+
+    fn part1(&self) -> Answer {
+        return task1::solve(&self.init_seq).into();
+    }
+
+    fn part2(&self) -> Answer {
+        return task2::solve(&self.init_seq).into();
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE_INPUT: &str = "\
+rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7
+";
@@ -1,13 +1,18 @@
+use aoc_helper::Solver;
+
+use crate::Day15;
 use crate::utils::InitSequence;
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_example_input() {
-        let init_seq = InitSequence::parse("../input/15_1_example_input.txt");
+        let init_seq = InitSequence::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
         let hashes = init_seq.hash_steps();
 
         assert_eq!(hashes, vec![30, 253, 97, 47, 14, 180, 9, 197, 48, 214, 231]);
@@ -17,10 +22,14 @@ mod tests {
     }
 }
 
-pub fn run_task() {
-    let init_seq = InitSequence::parse("input/15_1_input.txt");
+pub fn solve(init_seq: &InitSequence) -> u64 {
     let hashes = init_seq.hash_steps();
 
-    let sum = hashes.iter().sum::<u64>();
-    println!("Sum is: {}", sum);
-}
\ No newline at end of file
+    return hashes.iter().sum::<u64>();
+}
+
+pub fn run_task(file: &str) {
+    let day = Day15::parse_file(file).unwrap();
+
+    println!("Sum is: {}", day.part1());
+}
@@ -1,22 +1,30 @@
+use aoc_helper::Solver;
+
+use crate::Day15;
 use crate::utils::InitSequence;
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_example_input() {
-        let init_seq = InitSequence::parse("../input/15_1_example_input.txt");
+        let init_seq = InitSequence::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
         let focusing_power = init_seq.calculate_focusing_power();
 
         assert_eq!(focusing_power, 145)
     }
 }
 
-pub fn run_task() {
-    let init_seq = InitSequence::parse("input/15_1_input.txt");
-    let focusing_power = init_seq.calculate_focusing_power();
+pub fn solve(init_seq: &InitSequence) -> u64 {
+    return init_seq.calculate_focusing_power();
+}
+
+pub fn run_task(file: &str) {
+    let day = Day15::parse_file(file).unwrap();
 
-    println!("Focusing power: {}", focusing_power);
-}
\ No newline at end of file
+    println!("Focusing power: {}", day.part2());
+}
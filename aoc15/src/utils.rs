@@ -1,5 +1,7 @@
 use std::collections::VecDeque;
 
+use aoc_helper::{Input, ParseError};
+
 struct Lens {
     label: String,
     focal_length: u64
@@ -128,10 +130,29 @@ impl InitSequence {
         self.steps.iter().map(|step| Self::hash_step(&step)).collect()
     }
 
-    pub fn parse(file: &str) -> InitSequence {
-        let line = aoc_helper::read_lines(file).first().unwrap().clone();
-        let steps: Vec<String> = line.split(',').map(|s| s.to_owned()).collect();
+    fn is_valid_step(step: &str) -> bool {
+        if let Some(label) = step.strip_suffix('-') {
+            return !label.is_empty();
+        }
+
+        return match step.split_once('=') {
+            Some((label, focal_len)) => !label.is_empty() && matches!(focal_len.parse::<u64>(), Ok(1..=9)),
+            None => false
+        };
+    }
+
+    pub fn parse(input: &Input) -> Result<InitSequence, ParseError> {
+        let lines = input.lines();
+        let line = lines.first().ok_or_else(|| input.end("an initialization sequence"))?;
+
+        for step in line.text.split(',') {
+            if !Self::is_valid_step(step) {
+                return Err(line.error(step, "<label>- or <label>=<focal length 1-9>"));
+            }
+        }
+
+        let steps: Vec<String> = line.text.split(',').map(|s| s.to_owned()).collect();
 
-        InitSequence { steps: steps }
+        Ok(InitSequence { steps: steps })
     }
 }
\ No newline at end of file
@@ -0,0 +1,38 @@
+pub mod task1;
+pub mod task2;
+pub mod utils;
+
+use aoc_helper::{Answer, Input, ParseError, Solver};
+
+use crate::utils::Contraption;
+
+pub struct Day16 {
+    contraption: Contraption
+}
+
+impl Solver for Day16 {
+    fn parse_input(input: &Input) -> Result<Self, ParseError> {
+        return Ok(Day16 { contraption: Contraption::parse(input)? });
+    }
+
+    fn part1(&self) -> Answer {
+        return task1::solve(self.contraption.clone()).into();
+    }
+
+    fn part2(&self) -> Answer {
+        return task2::solve(self.contraption.clone()).into();
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE_INPUT: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....
+";
@@ -1,8 +1,6 @@
-mod task1;
-mod task2;
-mod utils;
+use aoc16::{task1, task2};
 
 fn main() {
-    aoc_helper::benchmark(task1::run_task);
-    aoc_helper::benchmark(task2::run_task);
+    aoc_helper::benchmark(|| task1::run_task(&aoc_helper::input_path(16, 1)));
+    aoc_helper::benchmark(|| task2::run_task(&aoc_helper::input_path(16, 2)));
 }
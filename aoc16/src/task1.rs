@@ -1,22 +1,30 @@
+use aoc_helper::Solver;
+
+use crate::Day16;
 use crate::utils::Contraption;
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_example_input() {
-        let mut contraption = Contraption::parse("../input/16_1_example_input.txt");
+        let mut contraption = Contraption::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
         let energized_tiles = contraption.energize_tiles();
 
         assert_eq!(energized_tiles, 46);
     }
 }
 
-pub fn run_task() {
-    let mut contraption = Contraption::parse("input/16_1_input.txt");
-    let energized_tiles = contraption.energize_tiles();
+pub fn solve(mut contraption: Contraption) -> u64 {
+    return contraption.energize_tiles();
+}
+
+pub fn run_task(file: &str) {
+    let day = Day16::parse_file(file).unwrap();
 
-    println!("Energized tiles: {}", energized_tiles);
-}
\ No newline at end of file
+    println!("Energized tiles: {}", day.part1());
+}
@@ -1,22 +1,30 @@
+use aoc_helper::Solver;
+
+use crate::Day16;
 use crate::utils::Contraption;
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_example_input() {
-        let mut contraption = Contraption::parse("../input/16_1_example_input.txt");
+        let contraption = Contraption::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
         let max_energized_tiles = contraption.find_max_tiles_energized();
 
         assert_eq!(max_energized_tiles, 51);
     }
 }
 
-pub fn run_task() {
-    let mut contraption = Contraption::parse("input/16_1_input.txt");
-    let max_energized_tiles = contraption.find_max_tiles_energized();
+pub fn solve(contraption: Contraption) -> u64 {
+    return contraption.find_max_tiles_energized();
+}
+
+pub fn run_task(file: &str) {
+    let day = Day16::parse_file(file).unwrap();
 
-    println!("Max energized tiles: {}", max_energized_tiles);
-}
\ No newline at end of file
+    println!("Max energized tiles: {}", day.part2());
+}
@@ -1,23 +1,19 @@
 use std::collections::VecDeque;
 
+use aoc_helper::{Input, ParseError};
+use aoc_helper::geom::{Direction, Pos};
+use aoc_helper::grid::Grid;
+use aoc_helper::parallel::Executor;
 
-#[derive(Debug, Copy, Clone)]
-enum Direction {
-    North = 0,
-    West = 1,
-    South = 2,
-    East = 3
-}
-
+#[derive(Clone)]
 struct Tile {
     c: char,
     visited: Vec<bool>
 }
 
+#[derive(Clone)]
 pub struct Contraption {
-    layout: Vec<Vec<Tile>>,
-    height: usize,
-    width: usize
+    layout: Grid<Tile>
 }
 
 const EMPTY: char = '.';
@@ -43,58 +39,13 @@ impl Tile {
 
 impl Contraption {
     fn reset_tiles(&mut self){
-        self.layout.iter_mut().for_each(|row| {
-            row.iter_mut().for_each(|tile| {
-                tile.visited.iter_mut().for_each(|b| *b = false);
-            });
+        self.layout.values_mut().for_each(|tile| {
+            tile.visited.iter_mut().for_each(|b| *b = false);
         });
     }
 
     fn calculate_energized_tiles(&self) -> u64 {
-        let mut sum = 0;
-
-        self.layout.iter().for_each(|row| {
-            row.iter().for_each(|tile| {
-                if tile.visited.iter().any(|b| *b == true) {
-                    sum += 1;
-                }
-            });
-        });
-
-        return sum;
-    }
-
-    fn calculate_next_position(pos: &(usize, usize), next_direction: Direction, height: usize, width: usize) -> Option<(usize, usize)> {
-        let mut next_pos = (pos.0, pos.1);
-        
-        match next_direction {
-            Direction::North => {
-                if next_pos.0 > 0 {
-                    next_pos.0 -= 1;
-                }
-            },
-            Direction::South => {
-                if next_pos.0 + 1 < height {
-                    next_pos.0 += 1;
-                }
-            },
-            Direction::West => {
-                if next_pos.1 > 0 {
-                    next_pos.1 -= 1;
-                }
-            },
-            Direction::East => {
-                if next_pos.1 + 1 < width {
-                    next_pos.1 += 1;
-                }
-            },
-        }
-
-        if next_pos == *pos {
-            return None;
-        }
-
-        return Some(next_pos);
+        return self.layout.values().filter(|tile| tile.visited.iter().any(|b| *b == true)).count() as u64;
     }
 
     fn next_directions(c: char, heading_dir: Direction) -> Vec<Direction> {
@@ -132,8 +83,8 @@ impl Contraption {
         }
     }
 
-    fn energize_tile(&mut self, pos: (usize, usize), heading_dir: Direction, queue: &mut VecDeque<((usize, usize), Direction)>) {
-        let tile = &mut self.layout[pos.0][pos.1];
+    fn energize_tile(&mut self, pos: Pos, heading_dir: Direction, queue: &mut VecDeque<(Pos, Direction)>) {
+        let tile = &mut self.layout[pos];
 
         // Prevent loops + register precense
         if tile.prevent_loop(&heading_dir) {
@@ -145,39 +96,41 @@ impl Contraption {
         let next_directions = Self::next_directions(tile.c, heading_dir);
 
         for next_direction in next_directions.into_iter() {
-            let next_pos = Self::calculate_next_position(&pos, next_direction, self.height, self.width);
-
-            if next_pos.is_none() {
-                continue;
+            if let Some(next_pos) = self.layout.step(pos, next_direction) {
+                queue.push_back((next_pos, next_direction));
             }
-
-            queue.push_back((next_pos.unwrap(), next_direction));
         }
     }
 
-    pub fn find_max_tiles_energized(&mut self) -> u64 {
-        let mut vec_energized_tiles = Vec::new();
+    pub fn find_max_tiles_energized(&self) -> u64 {
+        let mut starts = Vec::new();
 
-        for y in 0..self.height {
-            for x in 0..self.width {
+        let height = self.layout.height();
+        let width = self.layout.width();
+
+        for y in 0..height {
+            for x in 0..width {
                 if y == 0 {
-                    vec_energized_tiles.push(self.test_energize_tiles((y, x), Direction::South));
-                } else if y == self.height - 1 {
-                    vec_energized_tiles.push(self.test_energize_tiles((y, x), Direction::North));
+                    starts.push((Pos::new(x, y), Direction::South));
+                } else if y == height - 1 {
+                    starts.push((Pos::new(x, y), Direction::North));
                 }
     
                 if x == 0 {
-                    vec_energized_tiles.push(self.test_energize_tiles((y, x), Direction::East));
-                } else if x == self.width - 1 {
-                    vec_energized_tiles.push(self.test_energize_tiles((y, x), Direction::West));
+                    starts.push((Pos::new(x, y), Direction::East));
+                } else if x == width - 1 {
+                    starts.push((Pos::new(x, y), Direction::West));
                 }
             }
         }
 
+        // Every thread energizes its own copy of the tiles, which are reset after every start
+        let vec_energized_tiles = Executor::default().map_init(starts, || self.clone(), |contraption, (pos, direction)| contraption.test_energize_tiles(pos, direction));
+
         return *vec_energized_tiles.iter().max().unwrap();
     }
 
-    fn test_energize_tiles(&mut self, start_pos: (usize, usize), start_direction: Direction) -> u64 {
+    fn test_energize_tiles(&mut self, start_pos: Pos, start_direction: Direction) -> u64 {
         // Use queue approach instead of recursion (otherwise stack overflow)
         let mut queue = VecDeque::new();
         queue.push_back((start_pos, start_direction));
@@ -194,18 +147,12 @@ impl Contraption {
     }
 
     pub fn energize_tiles(&mut self) -> u64 {
-        self.test_energize_tiles((0, 0), Direction::East)
+        self.test_energize_tiles(Pos::new(0, 0), Direction::East)
     }
 
-    pub fn parse(file: &str) -> Contraption {
-        let lines = aoc_helper::read_lines(file);
-        let layout: Vec<Vec<Tile>> = lines.into_iter().map(|line| {
-            line.chars().map(|c| Tile::new(c)).collect::<Vec<Tile>>() // Line vec
-        }).collect();
-
-        let height = layout.len();
-        let width = layout.first().unwrap().len();
+    pub fn parse(input: &Input) -> Result<Contraption, ParseError> {
+        let layout = Grid::parse(input, "./\\|-")?.map(|c| Tile::new(*c));
 
-        Contraption { layout: layout, height: height, width: width }
+        Ok(Contraption { layout: layout })
     }
 }
\ No newline at end of file
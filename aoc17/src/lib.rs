@@ -0,0 +1,42 @@
+pub mod task1;
+pub mod task2;
+pub mod utils;
+
+use aoc_helper::{Answer, Input, ParseError, Solver};
+
+use crate::utils::HeatLossMap;
+
+pub struct Day17 {
+    heat_loss_map: HeatLossMap
+}
+
+impl Solver for Day17 {
+    fn parse_input(input: &Input) -> Result<Self, ParseError> {
+        return Ok(Day17 { heat_loss_map: HeatLossMap::parse(input)? });
+    }
+
+    fn part1(&self) -> Answer {
+        return task1::solve(&self.heat_loss_map).into();
+    }
+
+    fn part2(&self) -> Answer {
+        return task2::solve(&self.heat_loss_map).into();
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE_INPUT: &str = "\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+";
@@ -1,24 +1,48 @@
-use crate::utils::HeatLossMap;
+use aoc_helper::Solver;
+
+use crate::Day17;
+use crate::utils::{CrucibleRules, HeatLossMap};
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
+
+    const EXAMPLE_ROUTE: &str = "\
+2>>34^>>>1323
+32v>>>35v5623
+32552456v>>54
+3446585845v52
+4546657867v>6
+14385987984v4
+44578769877v6
+36378779796v>
+465496798688v
+456467998645v
+12246868655<v
+25465488877v5
+43226746555v>
+";
 
     #[test]
     fn test_example_input() {
-        let mut heat_loss_map = HeatLossMap::parse("../input/17_1_example_input.txt");
+        let heat_loss_map = HeatLossMap::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
 
-        let heat_loss_lowest = heat_loss_map.find_lowest_heat_loss_path(false);
+        let route = heat_loss_map.find_lowest_heat_loss_path(&CrucibleRules::NORMAL).unwrap();
 
-        assert_eq!(heat_loss_lowest, 102);
+        assert_eq!(route.heat_loss, 102);
+        assert_eq!(heat_loss_map.render_route(&route), EXAMPLE_ROUTE);
     }
 }
 
-pub fn run_task() {
-    let mut heat_loss_map = HeatLossMap::parse("input/17_1_input.txt");
+pub fn solve(heat_loss_map: &HeatLossMap) -> Option<u64> {
+    return heat_loss_map.find_lowest_heat_loss_path(&CrucibleRules::NORMAL).map(|route| route.heat_loss);
+}
 
-    let heat_loss_lowest = heat_loss_map.find_lowest_heat_loss_path(false);
+pub fn run_task(file: &str) {
+    let day = Day17::parse_file(file).unwrap();
 
-    println!("Lowest heat loss: {}", heat_loss_lowest);
-}
\ No newline at end of file
+    println!("Lowest heat loss: {}", day.part1());
+}
@@ -1,28 +1,80 @@
-use crate::utils::HeatLossMap;
+use aoc_helper::Solver;
+
+use crate::Day17;
+use crate::utils::{CrucibleRules, HeatLossMap};
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_helper::{Answer, Input};
+    use crate::EXAMPLE_INPUT;
+
+    const EXAMPLE_INPUT_2: &str = "\
+111111111111
+999999999991
+999999999991
+999999999991
+999999999991
+";
+
+    const EXAMPLE_ROUTE: &str = "\
+2>>>>>>>>1323
+32154535v5623
+32552456v4254
+34465858v5452
+45466578v>>>>
+143859879845v
+445787698776v
+363787797965v
+465496798688v
+456467998645v
+122468686556v
+254654888773v
+432267465553v
+";
+
+    const EXAMPLE_ROUTE_2: &str = "\
+1>>>>>>>1111
+9999999v9991
+9999999v9991
+9999999v9991
+9999999v>>>>
+";
 
     #[test]
     fn test_example_input() {
-        let mut heat_loss_map_1 = HeatLossMap::parse("../input/17_1_example_input.txt");
-        let heat_loss_lowest_1 = heat_loss_map_1.find_lowest_heat_loss_path(true);
-        assert_eq!(heat_loss_lowest_1, 94);
+        let heat_loss_map_1 = HeatLossMap::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
+        let route_1 = heat_loss_map_1.find_lowest_heat_loss_path(&CrucibleRules::ULTRA).unwrap();
+        assert_eq!(route_1.heat_loss, 94);
+        assert_eq!(heat_loss_map_1.render_route(&route_1), EXAMPLE_ROUTE);
 
-        println!("--------");
+        let heat_loss_map_2 = HeatLossMap::parse(&Input::new(EXAMPLE_INPUT_2)).unwrap();
+        let route_2 = heat_loss_map_2.find_lowest_heat_loss_path(&CrucibleRules::ULTRA).unwrap();
+        assert_eq!(route_2.heat_loss, 71);
+        assert_eq!(heat_loss_map_2.render_route(&route_2), EXAMPLE_ROUTE_2);
+    }
 
-        let mut heat_loss_map_2 = HeatLossMap::parse("../input/17_2_example_input.txt");
-        let heat_loss_lowest_2 = heat_loss_map_2.find_lowest_heat_loss_path(true);
-        assert_eq!(heat_loss_lowest_2, 71);
+    #[test]
+    fn test_unreachable_end() {
+        // The crucible is already at the end of a single block
+        let day = Day17::parse("7\n").unwrap();
+        assert_eq!(day.part1(), Answer::from(0));
+        assert_eq!(day.part2(), Answer::from(0));
+
+        // An ultra crucible can't stop after only three blocks
+        let day = Day17::parse("1111\n").unwrap();
+        assert_eq!(day.part1(), Answer::from(3));
+        assert_eq!(day.part2(), Answer::Unsolved);
     }
 }
 
-pub fn run_task() {
-    let mut heat_loss_map = HeatLossMap::parse("input/17_1_input.txt");
+pub fn solve(heat_loss_map: &HeatLossMap) -> Option<u64> {
+    return heat_loss_map.find_lowest_heat_loss_path(&CrucibleRules::ULTRA).map(|route| route.heat_loss);
+}
 
-    let heat_loss_lowest = heat_loss_map.find_lowest_heat_loss_path(true);
+pub fn run_task(file: &str) {
+    let day = Day17::parse_file(file).unwrap();
 
-    println!("Lowest heat loss (ultra crucible): {}", heat_loss_lowest);
-}
\ No newline at end of file
+    println!("Lowest heat loss (ultra crucible): {}", day.part2());
+}
@@ -1,285 +1,188 @@
-use std::collections::{VecDeque, HashMap};
-
-#[derive(PartialEq, Eq, Hash, Copy, Clone)]
-enum Direction {
-    North = 0,
-    West = 1,
-    South = 2,
-    East = 3
+use aoc_helper::{Input, ParseError};
+use aoc_helper::geom::{Direction, Pos};
+use aoc_helper::grid::Grid;
+use aoc_helper::search;
+
+/// Where a crucible is, where it is heading and how many blocks it moved in that direction
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+struct Crucible {
+    pos: Pos,
+    direction: Direction,
+    single_direction_step_count: u32
 }
 
-#[derive(PartialEq, Eq, Hash, Copy, Clone)]
-struct EnterBlockInfo {
-    direction: Direction,
-    single_direction_step_count: i32
+/// Route taken by a crucible, as the blocks it enters and the direction it moves in to enter them
+#[derive(Debug, PartialEq)]
+pub struct Route {
+    pub heat_loss: u64,
+    pub start: Pos,
+    pub steps: Vec<(Pos, Direction)>
 }
 
-struct Block {
-    heat_loss: u64,
-    heat_loss_map: HashMap<EnterBlockInfo, u64> // Maps "enter block info" to accumulated_heat_loss
+/// How a crucible is allowed to move
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrucibleRules {
+    /// Blocks to move in a single direction before the crucible can turn or stop at the end
+    pub min_straight: u32,
+    /// Blocks the crucible can move in a single direction at most
+    pub max_straight: u32,
+    /// Whether the crucible can turn around to the block it came from
+    pub can_reverse: bool,
+    /// Extra heat lost for every turn, reversing counts as two turns
+    pub turn_cost: u64
 }
 
-struct TestBlock {
-    pos: (usize, usize),
-    accumulated_heat_loss: u64,
-    enter_block_info: EnterBlockInfo,
-    path: Vec<((usize, usize), Direction)>
+impl CrucibleRules {
+    /// Normal crucible: at most three blocks in one direction and it can't reverse
+    pub const NORMAL: CrucibleRules = CrucibleRules { min_straight: 1, max_straight: 3, can_reverse: false, turn_cost: 0 };
+
+    /// Ultra crucible: at least four blocks in one direction (even in the end!) and at most ten
+    pub const ULTRA: CrucibleRules = CrucibleRules { min_straight: 4, max_straight: 10, can_reverse: false, turn_cost: 0 };
 }
 
+#[derive(Clone)]
 pub struct HeatLossMap {
-    map: Vec<Vec<Block>>,
-    height: usize,
-    width: usize
+    map: Grid<u64>
 }
 
-const DIRECTION_COUNT: u32 = 4;
-const SINGLE_DIRECTION_STEP_COUNT_MAX: i32 = 3;
-
-const ULTRA_CRUCIBLE_SINGLE_DIR_STEP_COUNT_MIN: i32 = 4;
-const ULTRA_CRUCIBLE_SINGLE_DIR_STEP_COUNT_MAX: i32 = 10;
-
-
-impl Direction {
-    fn from_direction(direction: Direction, left_or_right: Direction) -> Direction {
-        let mut direction_number: i32;
+impl HeatLossMap {
+    // Move forwards, left, right or back as far as the rules allow, with the heat lost by doing so
+    fn crucible_moves(&self, crucible: &Crucible, rules: &CrucibleRules) -> Vec<(Crucible, u64)> {
+        let mut moves = Vec::new();
 
-        if left_or_right == Direction::West {
-            direction_number = (direction as i32 + 1) % (DIRECTION_COUNT as i32);
-        } else if left_or_right == Direction::East {
-            direction_number = (direction as i32 - 1) % (DIRECTION_COUNT as i32);
-        } else {
-            panic!("Direction is not left or right (west or east)");
+        // Check if we can walk in the same direction
+        if crucible.single_direction_step_count < rules.max_straight {
+            moves.push((crucible.direction, crucible.single_direction_step_count + 1, 0));
         }
 
-        // Modulo can return negative number
-        if direction_number < 0 {
-            direction_number += 4; 
-        }
+        // Left & right (and back)
+        if crucible.single_direction_step_count >= rules.min_straight {
+            moves.push((crucible.direction.turn_left(), 1, rules.turn_cost));
+            moves.push((crucible.direction.turn_right(), 1, rules.turn_cost));
 
-        // TODO: Yikes!
-        match direction_number {
-            0 => Direction::North,
-            1 => Direction::West,
-            2 => Direction::South,
-            3 => Direction::East,
-            _ => panic!("Direction number out of bounds: {}", direction_number)
+            if rules.can_reverse {
+                moves.push((crucible.direction.reverse(), 1, 2 * rules.turn_cost));
+            }
         }
-    }
-}
 
-impl Block {
-    fn new(heat_loss: u64) -> Block {
-        Block { heat_loss: heat_loss, heat_loss_map: HashMap::new() }
+        return moves.into_iter()
+            .filter(|(_, step_count, _)| *step_count <= rules.max_straight)
+            .filter_map(|(direction, step_count, turn_cost)| {
+                let pos = self.map.step(crucible.pos, direction)?;
+                Some((Crucible { pos: pos, direction: direction, single_direction_step_count: step_count }, self.map[pos] + turn_cost))
+            })
+            .collect();
     }
-}
 
-impl TestBlock {
-    fn create_new_relative_to(original: &TestBlock, direction: Direction, height: usize, width: usize) -> Option<TestBlock> {
-        // Determine new position
-        let new_direction: Direction;
-        if direction == Direction::North { // Forwards
-            new_direction = original.enter_block_info.direction;
-        } else { // Left or right
-            new_direction = Direction::from_direction(original.enter_block_info.direction, direction);
-        }
+    /// Route of the crucible from the top left to the bottom right block, losing the least heat
+    ///
+    /// `None` if the rules don't allow the crucible to reach the end
+    pub fn find_lowest_heat_loss_path(&self, rules: &CrucibleRules) -> Option<Route> {
+        let start_pos = Pos::new(0, 0);
+        let end_pos = Pos::new(self.map.width() - 1, self.map.height() - 1);
 
-        // Check bounds of new position
-        let mut new_pos = original.pos;
-        match new_direction {
-            Direction::North => {
-                if original.pos.0 <= 0 {
-                    return None;
-                }
-                new_pos.0 -= 1;
-            },
-            Direction::West => {
-                if original.pos.1 <= 0 {
-                    return None;
-                }
-                new_pos.1 -= 1;
-            },
-            Direction::South => {
-                if original.pos.0 + 1 >= height {
-                    return None;
-                }
-                new_pos.0 += 1;
-            },
-            Direction::East => {
-                if original.pos.1 + 1 >= width {
-                    return None;
-                }
-                new_pos.1 += 1;
-            }
+        // Note: The crucible doesn't have to move at all, so the minimum straight blocks don't apply
+        if start_pos == end_pos {
+            return Some(Route { heat_loss: 0, start: start_pos, steps: Vec::new() });
         }
 
-        // Create new test block
-        let mut test_block = TestBlock::new(new_pos, new_direction);
-        test_block.accumulated_heat_loss = original.accumulated_heat_loss;
-        if direction == Direction::North { // Inherit single_direction_step_count if going in same dir. This is increased when the move happens
-            test_block.enter_block_info.single_direction_step_count = original.enter_block_info.single_direction_step_count;
-        }
-        test_block.path = original.path.clone();
+        // The heat loss of the start block is not counted, as the crucible starts there
+        let starts = [Direction::East, Direction::South].map(|direction| Crucible { pos: start_pos, direction: direction, single_direction_step_count: 0 });
+        let is_end = |crucible: &Crucible| crucible.pos == end_pos && crucible.single_direction_step_count >= rules.min_straight;
 
-        return Some(test_block);
-    }
+        let path = search::dijkstra(starts, |crucible| self.crucible_moves(crucible, rules), is_end)?;
 
-    fn new(pos: (usize, usize), direction: Direction) -> TestBlock {
-        let enter_block_info = EnterBlockInfo { direction: direction, single_direction_step_count: 0 };
-        TestBlock { pos: pos, accumulated_heat_loss: 0, enter_block_info: enter_block_info, path: Vec::new() }
-    }
-}
+        let steps = path.states[1..].iter().map(|crucible| (crucible.pos, crucible.direction)).collect();
 
-// Normal crucible
-// At most three blocks in one direction
-// Can't reverse direction either
-impl HeatLossMap {
-    fn single_dir_step_count_max(ultra_crucible: bool) -> i32 {
-        if ultra_crucible {
-            ULTRA_CRUCIBLE_SINGLE_DIR_STEP_COUNT_MAX
-        } else {
-            SINGLE_DIRECTION_STEP_COUNT_MAX
-        }
+        return Some(Route { heat_loss: path.cost, start: start_pos, steps: steps });
     }
 
-    fn single_dir_step_count_min(ultra_crucible: bool) -> i32 {
-        if ultra_crucible {
-            ULTRA_CRUCIBLE_SINGLE_DIR_STEP_COUNT_MIN
-        } else {
-            0
+    /// The heat loss map with arrows on the blocks of the route, pointing in the direction the crucible moved
+    pub fn render_route(&self, route: &Route) -> String {
+        let mut map = self.map.map(|heat_loss| char::from_digit(*heat_loss as u32, 10).unwrap());
+        for (pos, direction) in route.steps.iter() {
+            map[*pos] = direction.arrow();
         }
-    }
 
-    fn get_block_mut(&mut self, pos: &(usize, usize)) -> &mut Block {
-        &mut self.map[pos.0][pos.1]
+        return map.to_string();
     }
 
-    fn get_block(&self, pos: &(usize, usize)) -> &Block {
-        &self.map[pos.0][pos.1]
-    }
+    pub fn parse(input: &Input) -> Result<HeatLossMap, ParseError> {
+        let map = Grid::parse(input, "0123456789")?.map(|c| c.to_digit(10).unwrap() as u64);
 
-    fn push_back_if_some(queue: &mut VecDeque<TestBlock>, test_block: Option<TestBlock>) {
-        if let Some(tmp) = test_block {
-            queue.push_back(tmp);
-        }
+        Ok(HeatLossMap { map: map })
     }
+}
 
-    fn add_new_blocks_to_test(height: usize, width: usize, test_block: &TestBlock, queue: &mut VecDeque<TestBlock>, ultra_crucible: bool) {
-        // Check if we can walk in the same direction
-        if test_block.enter_block_info.single_direction_step_count < Self::single_dir_step_count_max(ultra_crucible) {
-            Self::push_back_if_some(queue, TestBlock::create_new_relative_to(test_block, Direction::North, height, width));
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EXAMPLE_INPUT;
 
-        // Left & right
-        if test_block.enter_block_info.single_direction_step_count >= Self::single_dir_step_count_min(ultra_crucible) {
-            Self::push_back_if_some(queue, TestBlock::create_new_relative_to(test_block, Direction::West, height, width));
-            Self::push_back_if_some(queue, TestBlock::create_new_relative_to(test_block, Direction::East, height, width));
-        }
+    fn rules(min_straight: u32, max_straight: u32) -> CrucibleRules {
+        return CrucibleRules { min_straight: min_straight, max_straight: max_straight, can_reverse: false, turn_cost: 0 };
     }
 
-    fn enter_block(&mut self, mut test_block: TestBlock, queue: &mut VecDeque<TestBlock>, ultra_crucible: bool) {
-        let block: &mut Block = self.get_block_mut(&test_block.pos);
-
-        // Increase heat loss
-        test_block.accumulated_heat_loss += block.heat_loss;
-
-        // Check if block has been entered before, in the same direction and same single_direction_step_count
-        if let Some(accumulated_heat_loss) = block.heat_loss_map.get(&test_block.enter_block_info) {
-            if *accumulated_heat_loss <= test_block.accumulated_heat_loss {
-                // There is already a better way!
-                return;
+    // Lengths of the straight parts of the route
+    fn straight_lengths(route: &Route) -> Vec<usize> {
+        let mut lengths = vec![1];
+        for step in route.steps.windows(2) {
+            if step[0].1 == step[1].1 {
+                *lengths.last_mut().unwrap() += 1;
+            } else {
+                lengths.push(1);
             }
-            // Note: Any offspring of the previously best "test block" could still be in the queue and slow down performance!
         }
-        block.heat_loss_map.insert(test_block.enter_block_info, test_block.accumulated_heat_loss);
-
-        test_block.path.push((test_block.pos, test_block.enter_block_info.direction));
 
-        // Add new blocks to try/test
-        Self::add_new_blocks_to_test(self.height, self.width, &test_block, queue, ultra_crucible);
+        return lengths;
     }
 
-    // Ultra crucible
-    // Move a minimum of four block in single direction before turn (even in the end!)
-    // Move a maximum of ten blocks in single direction 
-
-    pub fn find_lowest_heat_loss_path(&mut self, ultra_crucible: bool) -> u64 {
-        let start_pos: (usize, usize) = (0, 0);
-        let end_pos: (usize, usize) = (self.height - 1, self.width - 1);
-
-        let mut lowest_heat_loss = u64::MAX;
-        let mut lowest_head_loss_test_block = TestBlock::new(start_pos, Direction::East);
-        let mut queue: VecDeque<TestBlock> = VecDeque::new();
-
-        let mut start_test_block_east = TestBlock::new(start_pos, Direction::East);
-        start_test_block_east.enter_block_info.single_direction_step_count = -1; // To compensate for instant +1
-        let mut start_test_block_south = TestBlock::new(start_pos, Direction::South);
-        start_test_block_south.enter_block_info.single_direction_step_count = -1;
+    #[test]
+    fn test_straight_limits() {
+        let heat_loss_map = HeatLossMap::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
 
+        let zigzag = heat_loss_map.find_lowest_heat_loss_path(&rules(1, 1)).unwrap();
+        assert_eq!(zigzag.heat_loss, 133);
+        assert!(straight_lengths(&zigzag).iter().all(|length| *length == 1));
 
-        queue.push_back(start_test_block_east);
-        queue.push_back(start_test_block_south);
+        let exact = heat_loss_map.find_lowest_heat_loss_path(&rules(2, 2)).unwrap();
+        assert_eq!(exact.heat_loss, 127);
+        assert!(straight_lengths(&exact).iter().all(|length| *length == 2));
 
-        let start_block_heat_loss = self.get_block(&start_pos).heat_loss;
-        let end_block_heat_loss = self.get_block(&end_pos).heat_loss;
+        let unlimited = heat_loss_map.find_lowest_heat_loss_path(&rules(1, 100)).unwrap();
+        assert_eq!(unlimited.heat_loss, 78);
+        assert_eq!(straight_lengths(&unlimited), vec![11, 1, 1, 11]);
 
-        while let Some(mut test_block) = queue.pop_front() {
-            // Increase step count here so (min/max step count) comparison becomes correct
-            test_block.enter_block_info.single_direction_step_count += 1;
-
-            // Don't walk to far in the same direction
-            if test_block.enter_block_info.single_direction_step_count > Self::single_dir_step_count_max(ultra_crucible) {
-                continue;
-            }
-
-            if test_block.pos == end_pos {
-                // Remove start block heat loss and add end block heat loss
-                test_block.accumulated_heat_loss += end_block_heat_loss - start_block_heat_loss;
-                
-                // Verify that the ultra crucible can stop here
-                if test_block.enter_block_info.single_direction_step_count < Self::single_dir_step_count_min(ultra_crucible) {
-                    continue;
-                }
+        assert_eq!(heat_loss_map.find_lowest_heat_loss_path(&rules(13, 20)), None);
+        assert_eq!(heat_loss_map.find_lowest_heat_loss_path(&rules(3, 2)), None);
+    }
 
-                test_block.path.push((test_block.pos, test_block.enter_block_info.direction));
+    #[test]
+    fn test_turn_cost() {
+        let heat_loss_map = HeatLossMap::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
 
-                if test_block.accumulated_heat_loss < lowest_heat_loss {
-                    lowest_heat_loss = test_block.accumulated_heat_loss;
-                    lowest_head_loss_test_block = test_block;
-                }
+        // The part 2 route only turns three times, so it stays the cheapest
+        let ultra = heat_loss_map.find_lowest_heat_loss_path(&CrucibleRules { turn_cost: 100, ..CrucibleRules::ULTRA }).unwrap();
+        assert_eq!(ultra.heat_loss, 94 + 3 * 100);
 
-                continue;
-            }
-            
-            self.enter_block(test_block, &mut queue, ultra_crucible);
-        }
-        
-        // Note: for debugging, slows down performance pretty much!
-        let mut it = lowest_head_loss_test_block.path.iter();
-        let mut prev_path = it.next().unwrap();
-        print!("{:?} ", prev_path.0);
-        it.for_each(|path| {
-            if prev_path.1 != path.1 {
-                println!();
-            }
-            print!("{:?} ", path.0);
-            prev_path = path;
-        });
-        println!();
-
-        return lowest_heat_loss;
+        let normal = heat_loss_map.find_lowest_heat_loss_path(&CrucibleRules { turn_cost: 5, ..CrucibleRules::NORMAL }).unwrap();
+        let turns = straight_lengths(&normal).len() as u64 - 1;
+        let blocks: u64 = normal.steps.iter().map(|(pos, _)| heat_loss_map.map[*pos]).sum();
+        assert_eq!(normal.heat_loss, 159);
+        assert_eq!(normal.heat_loss, blocks + 5 * turns);
     }
 
-    pub fn parse(file: &str) -> HeatLossMap {
-        let line = aoc_helper::read_lines(file);
-        let map = line.into_iter().map(|line| {
-            line.chars().map(|c| {
-                Block::new(c.to_digit(10).unwrap() as u64)
-            }).collect::<Vec<Block>>()
-        }).collect::<Vec<Vec<Block>>>();
-
-        let height = map.len();
-        let width = map.first().unwrap().len();
-
-        HeatLossMap { map: map, height: height, width: width }
+    #[test]
+    fn test_reverse() {
+        let heat_loss_map = HeatLossMap::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
+        let route = heat_loss_map.find_lowest_heat_loss_path(&CrucibleRules { can_reverse: true, ..CrucibleRules::NORMAL }).unwrap();
+        assert_eq!(route.heat_loss, 101);
+
+        // A single row can only be crossed by going back and forth
+        let corridor = HeatLossMap::parse(&Input::new("1111\n")).unwrap();
+        let reverse_rules = CrucibleRules { min_straight: 1, max_straight: 2, can_reverse: true, turn_cost: 0 };
+        assert_eq!(corridor.find_lowest_heat_loss_path(&rules(1, 2)), None);
+        assert_eq!(corridor.find_lowest_heat_loss_path(&reverse_rules).unwrap().heat_loss, 5);
+        assert_eq!(corridor.find_lowest_heat_loss_path(&CrucibleRules { turn_cost: 10, ..reverse_rules }).unwrap().heat_loss, 5 + 2 * 2 * 10);
     }
-}
\ No newline at end of file
+}
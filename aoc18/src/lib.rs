@@ -0,0 +1,47 @@
+pub mod task1;
+pub mod task2;
+pub mod utils;
+
+use aoc_helper::{Answer, Input, ParseError, Solver};
+
+use crate::utils::DigPlan;
+
+pub struct Day18 {
+    dig_plan: DigPlan,
+    dig_plan_swapped: DigPlan
+}
+
+impl Solver for Day18 {
+    fn parse_input(input: &Input) -> Result<Self, ParseError> {
+        return Ok(Day18 {
+            dig_plan: DigPlan::parse(input, false)?,
+            dig_plan_swapped: DigPlan::parse(input, true)?
+        });
+    }
+
+    fn part1(&self) -> Answer {
+        return task1::solve(&self.dig_plan).into();
+    }
+
+    fn part2(&self) -> Answer {
+        return task2::solve(&self.dig_plan_swapped).into();
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE_INPUT: &str = "\
+R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)
+";
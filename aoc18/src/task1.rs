@@ -1,22 +1,30 @@
+use aoc_helper::Solver;
+
+use crate::Day18;
 use crate::utils::DigPlan;
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_example_input() {
-        let dig_plan = DigPlan::parse("../input/18_1_example_input.txt", false);
+        let dig_plan = DigPlan::parse(&Input::new(EXAMPLE_INPUT), false).unwrap();
         let lava_capacity = dig_plan.get_lava_capacity();
 
         assert_eq!(lava_capacity, 62);
     }
 }
 
-pub fn run_task() {
-    let dig_plan = DigPlan::parse("input/18_1_input.txt", false);
-    let lava_capacity = dig_plan.get_lava_capacity();
+pub fn solve(dig_plan: &DigPlan) -> u64 {
+    return dig_plan.get_lava_capacity();
+}
+
+pub fn run_task(file: &str) {
+    let day = Day18::parse_file(file).unwrap();
 
-    println!("Lava capacity is: {}", lava_capacity);
-}
\ No newline at end of file
+    println!("Lava capacity is: {}", day.part1());
+}
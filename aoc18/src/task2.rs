@@ -1,22 +1,30 @@
+use aoc_helper::Solver;
+
+use crate::Day18;
 use crate::utils::DigPlan;
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_example_input() {
-        let dig_plan = DigPlan::parse("../input/18_1_example_input.txt", true);
+        let dig_plan = DigPlan::parse(&Input::new(EXAMPLE_INPUT), true).unwrap();
         let area = dig_plan.get_lava_capacity_optimized();
 
         assert_eq!(area, 952408144115);
     }
 }
 
-pub fn run_task() {
-    let dig_plan = DigPlan::parse("input/18_1_input.txt", true);
-    let area = dig_plan.get_lava_capacity_optimized();
+pub fn solve(dig_plan: &DigPlan) -> u64 {
+    return dig_plan.get_lava_capacity_optimized();
+}
+
+pub fn run_task(file: &str) {
+    let day = Day18::parse_file(file).unwrap();
 
-    println!("Area is: {}", area);
-}
\ No newline at end of file
+    println!("Area is: {}", day.part2());
+}
@@ -1,12 +1,10 @@
 use std::collections::VecDeque;
 
-enum Direction {
-    Up,
-    Left,
-    Down,
-    Right
-}
+use aoc_helper::{Input, Line, ParseError};
+use aoc_helper::geom::{Direction, Pos, Vec2};
+use aoc_helper::grid::Grid;
 
+#[derive(Clone)]
 struct Tile {
     c: char,
 }
@@ -20,46 +18,45 @@ pub struct DigPlan {
     instructions: Vec<DigInstruction>
 }
 
-impl Direction {
-    fn new(c: char) -> Direction {
-        match c {
-            'U' => Direction::Up,
-            'L' => Direction::Left,
-            'D' => Direction::Down,
-            'R' => Direction::Right,
-            _ => panic!("Unexpected char: {}", c)
-        }
-    }
-}
-
 impl DigInstruction {
-    fn parse_swapped(line: &str) -> DigInstruction {
-        let split_line = line.split(" ");
+    fn parse_swapped(line: &Line) -> Result<DigInstruction, ParseError> {
+        let split_line = line.text.split(" ");
         let right_side = split_line.last().unwrap();
 
-        let meters = u64::from_str_radix(&right_side[2..7], 16).unwrap();
-        let direction_number = u64::from_str_radix(&right_side[7..8], 16).unwrap();
+        let expected = "a color (#<5 hex digits meters><direction 0-3>)";
+        if right_side.len() != 9 || !right_side.starts_with("(#") || !right_side.ends_with(')') {
+            return Err(line.error(right_side, expected));
+        }
+
+        let meters = u64::from_str_radix(&right_side[2..7], 16).map_err(|_| line.error(right_side, expected))?;
+        let direction_number = u64::from_str_radix(&right_side[7..8], 16).map_err(|_| line.error(right_side, expected))?;
         let direction = match direction_number {
-            0 => Direction::Right,
-            1 => Direction::Down,
-            2 => Direction::Left,
-            3 => Direction::Up,
-            _ => panic!("Invalid direction number")
+            0 => Direction::East,
+            1 => Direction::South,
+            2 => Direction::West,
+            3 => Direction::North,
+            _ => return Err(line.error(&right_side[7..8], "a direction number 0-3"))
         };
 
-        DigInstruction { direction: direction, meters: meters }
+        Ok(DigInstruction { direction: direction, meters: meters })
     }
 
-    fn parse_normal(line: &str) -> DigInstruction {
-        let mut split_line = line.split(" ");
+    fn parse_normal(line: &Line) -> Result<DigInstruction, ParseError> {
+        let mut split_line = line.text.split(" ");
 
-        let direction = Direction::new(split_line.next().unwrap().chars().next().unwrap());
-        let meters = split_line.next().unwrap().parse::<u64>().unwrap();
+        let direction_str = split_line.next().unwrap();
+        let mut direction_chars = direction_str.chars();
+        let direction = match (direction_chars.next().filter(|c| "ULDR".contains(*c)).and_then(Direction::parse), direction_chars.next()) {
+            (Some(direction), None) => direction,
+            _ => return Err(line.error(direction_str, "U, L, D or R"))
+        };
+        let meters_str = split_line.next().ok_or_else(|| line.end("meters"))?;
+        let meters = line.parse::<u64>(meters_str, "meters")?;
 
-        DigInstruction { direction: direction, meters: meters }
+        Ok(DigInstruction { direction: direction, meters: meters })
     }
 
-    fn parse(line: &str, swapped: bool) -> DigInstruction {
+    fn parse(line: &Line, swapped: bool) -> Result<DigInstruction, ParseError> {
         if !swapped {
             Self::parse_normal(line)
         } else {
@@ -69,21 +66,12 @@ impl DigInstruction {
 }
 
 impl DigPlan {
-    fn count_non_visited_tiles(map: &mut Vec<Vec<Tile>>) -> u64 {
-        let mut sum = 0;
-
-        for line in map.iter() {
-            for tile in line.iter() {
-                if tile.c != '*' {
-                    sum += 1;
-                }
-            }
-        }
-
-        return sum;
+    fn count_non_visited_tiles(map: &Grid<Tile>) -> u64 {
+        return map.values().filter(|tile| tile.c != '*').count() as u64;
     }
 
-    fn visit_tile(tile: &mut Tile, pos: (usize, usize), height: usize, width: usize, queue: &mut VecDeque<(usize, usize)>) {
+    fn visit_tile(map: &mut Grid<Tile>, pos: Pos, queue: &mut VecDeque<Pos>) {
+        let tile = &mut map[pos];
         if tile.c == '#' || tile.c == '*' {
             return;
         }
@@ -92,133 +80,68 @@ impl DigPlan {
         tile.c = '*';
 
         // A bit inefficient, but add all directions :D
-        if pos.0 > 0 {
-            queue.push_back((pos.0 - 1, pos.1));
-        }
-        if pos.0 + 1 < height {
-            queue.push_back((pos.0 + 1, pos.1));
-        }
-        if pos.1 > 0 {
-            queue.push_back((pos.0, pos.1 - 1));
-        }
-        if pos.1 + 1 < width {
-            queue.push_back((pos.0, pos.1 + 1));
+        for neighbour in map.neighbours4(pos.x, pos.y) {
+            queue.push_back(Pos::from(neighbour));
         }
     }
 
-    fn determine_capcity(map: &mut Vec<Vec<Tile>>) -> u64 {
+    fn determine_capcity(map: &mut Grid<Tile>) -> u64 {
         // Old good flood fill (requires an empty ring around map though)
 
-        let height = map.len();
-        let width = map.first().unwrap().len();
-
-        let start_pos = (0, 0);
+        let start_pos = Pos::new(0, 0);
         let mut queue = VecDeque::new();
 
         queue.push_back(start_pos);
 
         while let Some(pos) = queue.pop_front() {
-            Self::visit_tile(&mut map[pos.0][pos.1], pos, height, width, &mut queue);
+            Self::visit_tile(map, pos, &mut queue);
         }
 
         return Self::count_non_visited_tiles(map);
     }
 
-    fn expand_map(map: &mut Vec<Vec<Tile>>) {
-        let height = map.len();
-        let width = map.first().unwrap().len();
-
-        let up = map.first().unwrap().iter().any(|tile| tile.c == '#');
-        let down = map[height - 1].iter().any(|tile| tile.c == '#');
-        let left = map.iter().map(|line| line.first().unwrap()).any(|tile| tile.c == '#');
-        let right = map.iter().map(|line| &line[width - 1]).any(|tile| tile.c == '#');
-
-        if up {
-            let top_vec: Vec<Tile> = (0..width).into_iter().map(|_| Tile { c: '.'}).collect();
-            map.insert(0, top_vec);
-            //height = map.len();
-        }
-        
-        if down {
-            let top_vec: Vec<Tile> = (0..width).into_iter().map(|_| Tile { c: '.'}).collect();
-            map.push(top_vec);
-            //height = map.len();
-        }
-
-        for line_vec in map.iter_mut() {
-            if left {
-                line_vec.insert(0, Tile { c: '.' })
-            }
-            if right {
-                line_vec.push( Tile { c: '.' })
-            }
-        }
-    }
-    
-    fn create_map(trench: &Vec<(usize, usize)>, upper_bounds_inclusive: &(usize, usize)) -> Vec<Vec<Tile>> {
-        let mut map = Vec::new();
-        for _ in 0..upper_bounds_inclusive.0 + 1 {
-            let mut line_vec = Vec::new();
-            for _ in 0..upper_bounds_inclusive.1 + 1 {
-                line_vec.push(Tile { c: '.' })
-            }
-            map.push(line_vec);
-        }
+    fn create_map(trench: &Vec<Pos>, upper_bounds_inclusive: &Pos) -> Grid<Tile> {
+        let mut map = Grid::new(upper_bounds_inclusive.x + 1, upper_bounds_inclusive.y + 1, Tile { c: '.' });
 
         // Mark trench
         for coordinates in trench.iter() {
-            map[coordinates.0][coordinates.1].c = '#';
+            map[*coordinates].c = '#';
         }
 
         return map;
     }
 
-    fn determine_upper_bounds(trench: &Vec<(usize, usize)>) -> (usize, usize) {
-        let mut upper_bounds = (0, 0);
+    fn determine_upper_bounds(trench: &Vec<Pos>) -> Pos {
+        let mut upper_bounds = Pos::new(0, 0);
         for coordinates in trench.iter() {
-            if coordinates.0 > upper_bounds.0 {
-                upper_bounds.0 = coordinates.0;
-            }
-            if coordinates.1 > upper_bounds.1 {
-                upper_bounds.1 = coordinates.1
-            }
+            upper_bounds.x = upper_bounds.x.max(coordinates.x);
+            upper_bounds.y = upper_bounds.y.max(coordinates.y);
         }
 
         return upper_bounds;
     }
 
-    fn determine_lower_bounds(trench: &Vec<(isize, isize)>) -> (isize, isize) {
-        let mut lower_bounds = (isize::MAX, isize::MAX);
+    fn determine_lower_bounds(trench: &Vec<Vec2>) -> Vec2 {
+        let mut lower_bounds = Vec2::new(isize::MAX, isize::MAX);
         for coordinates in trench.iter() {
-            if coordinates.0 < lower_bounds.0 {
-                lower_bounds.0 = coordinates.0;
-            }
-            if coordinates.1 < lower_bounds.1 {
-                lower_bounds.1 = coordinates.1
-            }
+            lower_bounds.x = lower_bounds.x.min(coordinates.x);
+            lower_bounds.y = lower_bounds.y.min(coordinates.y);
         }
 
         return lower_bounds;
     }
 
-    fn convert_trench_to_usize(mut trench: Vec<(isize, isize)>) -> Vec<(usize, usize)> {
+    fn convert_trench_to_usize(trench: Vec<Vec2>) -> Vec<Pos> {
         let lower_bounds = Self::determine_lower_bounds(&trench);
 
         // Bump all coordinates up by the negative lower bounds
-        for coordinates in trench.iter_mut() {
-            if lower_bounds.0.is_negative() {
-                coordinates.0 -= lower_bounds.0;
-            }
-            if lower_bounds.1.is_negative() {
-                coordinates.1 -= lower_bounds.1;
-            }
-        }
+        let bump = Vec2::new(lower_bounds.x.min(0), lower_bounds.y.min(0));
 
-        trench.iter().map(|coordinates| (coordinates.0 as usize, coordinates.1 as usize)).collect()
+        trench.into_iter().map(|coordinates| (coordinates - bump).to_pos().unwrap()).collect()
     }
 
-    fn dig_out_trench(instructions: &Vec<DigInstruction>) -> Vec<(usize, usize)> {
-        let mut coordinates: (isize, isize) = (0, 0);
+    fn dig_out_trench(instructions: &Vec<DigInstruction>) -> Vec<Pos> {
+        let mut coordinates = Vec2::new(0, 0);
         let mut trench = Vec::new();
 
         // Push initial coordinates
@@ -226,12 +149,7 @@ impl DigPlan {
 
         for instruction in instructions.iter() {
             for _ in 0..instruction.meters {
-                match instruction.direction {
-                    Direction::Up => coordinates.0 -= 1,
-                    Direction::Left => coordinates.1 -= 1,
-                    Direction::Down => coordinates.0 += 1,
-                    Direction::Right => coordinates.1 += 1
-                }
+                coordinates += instruction.direction.offset();
                 trench.push(coordinates);
             }
         }
@@ -239,25 +157,25 @@ impl DigPlan {
         return Self::convert_trench_to_usize(trench);
     }
 
-    fn shoelace_formula(trench: &Vec<(usize, usize)>) -> f64 {
+    fn shoelace_formula(trench: &Vec<Pos>) -> f64 {
         let mut sum: i64 = 0;
 
         for i in 0..trench.len() {
-            let prev: (usize, usize);
+            let prev: Pos;
             if i == 0 {
                 prev = *trench.last().unwrap();
             } else {
                 prev = trench[i - 1];
             }
             let current = trench[i];
-            let next: (usize, usize);
+            let next: Pos;
             if i == trench.len() - 1 {
                 next = *trench.first().unwrap();
             } else {
                 next = trench[i + 1];
             }
 
-            sum += current.0 as i64 * (next.1 as i64 - prev.1 as i64)
+            sum += current.y as i64 * (next.x as i64 - prev.x as i64)
         }
 
         return (sum as f64 / 2f64).abs();
@@ -278,20 +196,15 @@ impl DigPlan {
         return (corners as f64 + sides as f64) / 2.0 + 1.0;
     }
 
-    fn dig_out_trench_part_2(instructions: &Vec<DigInstruction>) -> Vec<(usize, usize)> {
-        let mut coordinates: (isize, isize) = (0, 0);
+    fn dig_out_trench_part_2(instructions: &Vec<DigInstruction>) -> Vec<Pos> {
+        let mut coordinates = Vec2::new(0, 0);
         let mut trench = Vec::new();
 
         // Push initial coordinates
         trench.push(coordinates);
 
         for instruction in instructions.iter() {
-            match instruction.direction {
-                Direction::Up => coordinates.0 -= instruction.meters as isize,
-                Direction::Left => coordinates.1 -= instruction.meters as isize,
-                Direction::Down => coordinates.0 += instruction.meters as isize,
-                Direction::Right => coordinates.1 += instruction.meters as isize
-            }
+            coordinates += instruction.direction.offset() * instruction.meters as isize;
             trench.push(coordinates);
         }
 
@@ -320,16 +233,14 @@ impl DigPlan {
     pub fn get_lava_capacity(&self) -> u64 {
         let trench = Self::dig_out_trench(&self.instructions);
         let upper_bounds = Self::determine_upper_bounds(&trench);
-        let mut map = Self::create_map(&trench, &upper_bounds);
-        Self::expand_map(&mut map); // Note: can always cut it back into size so that trench is valid
+        let mut map = Self::create_map(&trench, &upper_bounds).padded(1, Tile { c: '.' }); // Note: can always cut it back into size so that trench is valid
 
         return Self::determine_capcity(&mut map);
     }
 
-    pub fn parse(file: &str, swapped: bool) -> DigPlan {
-        let lines = aoc_helper::read_lines(file);
-        let instructions = lines.iter().map(|line| DigInstruction::parse(line, swapped)).collect();
+    pub fn parse(input: &Input, swapped: bool) -> Result<DigPlan, ParseError> {
+        let instructions = input.lines().iter().map(|line| DigInstruction::parse(line, swapped)).collect::<Result<_, _>>()?;
 
-        return DigPlan { instructions: instructions };
+        return Ok(DigPlan { instructions: instructions });
     }
 }
\ No newline at end of file
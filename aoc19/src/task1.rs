@@ -1,13 +1,18 @@
+use aoc_helper::Solver;
+
+use crate::Day19;
 use crate::utils::ElfSystem;
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_example_input() {
-        let elf_system = ElfSystem::parse("../input/19_1_example_input.txt");
+        let elf_system = ElfSystem::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
         let accepted_parts = elf_system.get_accepted_parts();
         let sum_rating = ElfSystem::sum_parts_rating_numbers(&accepted_parts);
 
@@ -15,10 +20,14 @@ mod tests {
     }
 }
 
-pub fn run_task() {
-    let elf_system = ElfSystem::parse("input/19_1_input.txt");
+pub fn solve(elf_system: &ElfSystem) -> u64 {
     let accepted_parts = elf_system.get_accepted_parts();
-    let sum_rating = ElfSystem::sum_parts_rating_numbers(&accepted_parts);
 
-    println!("Sum rating is: {}", sum_rating);
-}
\ No newline at end of file
+    return ElfSystem::sum_parts_rating_numbers(&accepted_parts);
+}
+
+pub fn run_task(file: &str) {
+    let day = Day19::parse_file(file).unwrap();
+
+    println!("Sum rating is: {}", day.part1());
+}
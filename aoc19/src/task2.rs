@@ -1,22 +1,30 @@
+use aoc_helper::Solver;
+
+use crate::Day19;
 use crate::utils::ElfSystem;
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_example_input() {
-        let elf_system = ElfSystem::parse("../input/19_1_example_input.txt");
+        let elf_system = ElfSystem::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
         let distinct_combinations = elf_system.get_distinct_combinations();
 
         assert_eq!(distinct_combinations, 167409079868000);
     }
 }
 
-pub fn run_task() {
-    let elf_system = ElfSystem::parse("input/19_1_input.txt");
-    let distinct_combinations = elf_system.get_distinct_combinations();
+pub fn solve(elf_system: &ElfSystem) -> u64 {
+    return elf_system.get_distinct_combinations();
+}
+
+pub fn run_task(file: &str) {
+    let day = Day19::parse_file(file).unwrap();
 
-    println!("Distinct combinations: {}", distinct_combinations);
-}
\ No newline at end of file
+    println!("Distinct combinations: {}", day.part2());
+}
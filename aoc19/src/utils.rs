@@ -1,5 +1,6 @@
 use std::collections::VecDeque;
 use regex::Regex;
+use aoc_helper::{Input, Line, ParseError};
 
 enum Category {
     X,
@@ -51,13 +52,13 @@ pub struct ElfSystem {
 }
 
 impl Category {
-    fn new(c: char) -> Category {
-        match c {
-            'x' => Category::X,
-            'm' => Category::M,
-            'a' => Category::A,
-            's' => Category::S,
-            _ => panic!("Cannot parse Category from: {}", c)
+    fn parse(line: &Line, str: &str) -> Result<Category, ParseError> {
+        match str {
+            "x" => Ok(Category::X),
+            "m" => Ok(Category::M),
+            "a" => Ok(Category::A),
+            "s" => Ok(Category::S),
+            _ => Err(line.error(str, "a category (x, m, a or s)"))
         }
     }
 }
@@ -161,14 +162,15 @@ impl MachinePart {
         self.x + self.m + self.a + self.s
     }
 
-    fn parse(line: &str) -> MachinePart {
-        let re = Regex::new(r"\{x=([0-9]+),m=([0-9]+),a=([0-9]+),s=([0-9]+)\}").unwrap();
+    fn parse(line: &Line) -> Result<MachinePart, ParseError> {
+        let re = Regex::new(r"^\{x=([0-9]+),m=([0-9]+),a=([0-9]+),s=([0-9]+)\}$").unwrap();
 
-        for (_, [x, m, a, s]) in re.captures_iter(line).map(|c| c.extract()) {
-            return MachinePart { x: x.parse::<u64>().unwrap(), m: m.parse::<u64>().unwrap(), a: a.parse::<u64>().unwrap(), s: s.parse::<u64>().unwrap() };
+        for (_, [x, m, a, s]) in re.captures_iter(line.text).map(|c| c.extract()) {
+            let rating = |str| line.parse::<u64>(str, "a rating");
+            return Ok(MachinePart { x: rating(x)?, m: rating(m)?, a: rating(a)?, s: rating(s)? });
         }
 
-        panic!("Unparseable MachinePart: {}", line);
+        return Err(line.error(line.text, "a machine part ({x=<n>,m=<n>,a=<n>,s=<n>})"));
     }
 }
 
@@ -198,15 +200,16 @@ impl RuleCondition {
         self.comparator.compare(part.get_category_value(&self.category), self.value)
     }
 
-    fn parse(str: &str) -> RuleCondition {
+    fn parse(line: &Line, str: &str) -> Result<RuleCondition, ParseError> {
         let split_char = if str.contains('<') { '<' } else { '>' };
         let mut split = str.split(split_char);
 
-        let category = Category::new(split.next().unwrap().chars().next().unwrap());
+        let category = Category::parse(line, split.next().unwrap())?;
         let comparator = Comparator::new(split_char);
-        let value = split.next().unwrap().parse::<u64>().unwrap();
+        let value_str = split.next().ok_or_else(|| line.error(str, "a condition (<category><'<' or '>'><value>)"))?;
+        let value = line.parse::<u64>(value_str, "a value")?;
 
-        RuleCondition { category: category, comparator: comparator, value: value }
+        Ok(RuleCondition { category: category, comparator: comparator, value: value })
     }
 }
 
@@ -234,15 +237,15 @@ impl Rule {
         };
     }
 
-    fn parse(str: &str) -> Rule {
+    fn parse(line: &Line, str: &str) -> Result<Rule, ParseError> {
         let split: Vec<&str> = str.split(":").collect();
         if split.len() == 1 {
-            return Rule { condition: None, destination: split[0].to_string() };
+            return Ok(Rule { condition: None, destination: split[0].to_string() });
         } else if split.len() == 2 {
-            return Rule { condition: Some(RuleCondition::parse(split[0])), destination: split[1].to_string() };
+            return Ok(Rule { condition: Some(RuleCondition::parse(line, split[0])?), destination: split[1].to_string() });
         }
 
-        panic!("Unexpected rule split length");
+        return Err(line.error(str, "a rule (<condition>:<destination> or <destination>)"));
     }
 }
 
@@ -289,19 +292,19 @@ impl Workflow {
         panic!("Part was not processed in workflow!");
     }
 
-    fn parse_rules(str: &str) -> Vec<Rule> {
-        str.split(',').map(|tmp| Rule::parse(tmp)).collect()
+    fn parse_rules(line: &Line, str: &str) -> Result<Vec<Rule>, ParseError> {
+        str.split(',').map(|tmp| Rule::parse(line, tmp)).collect()
     }
     
-    fn parse(line: &str) -> Workflow {
-        let re = Regex::new(r"([a-z]+)\{(.*)\}").unwrap();
+    fn parse(line: &Line) -> Result<Workflow, ParseError> {
+        let re = Regex::new(r"^([a-z]+)\{(.*)\}$").unwrap();
 
-        for (_, [name, rules]) in re.captures_iter(line).map(|c| c.extract()) {
+        for (_, [name, rules]) in re.captures_iter(line.text).map(|c| c.extract()) {
             
-            return Workflow { name: name.to_owned(), rules: Self::parse_rules(rules) };
+            return Ok(Workflow { name: name.to_owned(), rules: Self::parse_rules(line, rules)? });
         }
 
-        panic!("Could not parse Workflow from: {}", line);
+        return Err(line.error(line.text, "a workflow (<name>{<rules>})"));
     }
 }
 
@@ -346,25 +349,29 @@ impl ElfSystem {
         return accepted_parts;
     }
 
-    pub fn parse(file: &str) -> ElfSystem {
-        let lines = aoc_helper::read_lines(file);
-        let mut it = lines.into_iter();
+    pub fn parse(input: &Input) -> Result<ElfSystem, ParseError> {
+        let lines = input.lines();
+        let mut it = lines.iter();
 
         // Parse workflows
         let mut workflows = Vec::new();
         while let Some(line) = it.next() {
-            if line.is_empty() {
+            if line.text.is_empty() {
                 break;
             }
-            workflows.push(Workflow::parse(&line));
+            workflows.push(Workflow::parse(line)?);
+        }
+
+        if !workflows.iter().any(|workflow| workflow.name == "in") {
+            return Err(input.end("a workflow named 'in'"));
         }
 
         // Parse machine parts
         let mut parts = Vec::new();
         while let Some(line) = it.next() {
-            parts.push(MachinePart::parse(&line));
+            parts.push(MachinePart::parse(line)?);
         }
 
-        ElfSystem { workflows: workflows, parts: parts }
+        Ok(ElfSystem { workflows: workflows, parts: parts })
     }
 }
\ No newline at end of file
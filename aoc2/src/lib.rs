@@ -0,0 +1,34 @@
+pub mod task1;
+pub mod task2;
+pub mod utils;
+
+use aoc_helper::{Answer, Input, ParseError, Solver};
+
+use crate::utils::Game;
+
+pub struct Day2 {
+    games: Vec<Game>
+}
+
+impl Solver for Day2 {
+    fn parse_input(input: &Input) -> Result<Self, ParseError> {
+        return Ok(Day2 { games: Game::parse_games(input)? });
+    }
+
+    fn part1(&self) -> Answer {
+        return task1::solve(&self.games).into();
+    }
+
+    fn part2(&self) -> Answer {
+        return task2::solve(&self.games).into();
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE_INPUT: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
@@ -1,12 +1,17 @@
+use aoc_helper::Solver;
+
+use crate::Day2;
 use crate::utils::{Game, GameSet};
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_task_2_example_input() {
-        let games = Game::parse_games("../input/2_1_example_input.txt");
+        let games = Game::parse_games(&Input::new(EXAMPLE_INPUT)).unwrap();
 
         // For printing
         // for game in games.iter() {
@@ -30,14 +35,18 @@ mod tests {
     }
 }
 
-pub fn run_task() {
-    let games = Game::parse_games("input/2_1_input.txt");
+pub fn solve(games: &Vec<Game>) -> u32 {
     let bag = GameSet{ red: 12, green: 13, blue: 14 };
 
-    let possible_games = Game::find_possible_games(&bag, &games);
+    let possible_games = Game::find_possible_games(&bag, games);
 
     let possible_game_ids: Vec<u32> = possible_games.iter().map(|g| g.id).collect();
 
-    let sum: u32 = possible_game_ids.iter().sum();
-    println!("Sum is {}", sum);
-}
\ No newline at end of file
+    return possible_game_ids.iter().sum();
+}
+
+pub fn run_task(file: &str) {
+    let day = Day2::parse_file(file).unwrap();
+
+    println!("Sum is {}", day.part1());
+}
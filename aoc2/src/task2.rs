@@ -1,12 +1,17 @@
+use aoc_helper::Solver;
+
+use crate::Day2;
 use crate::utils::Game;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_task_2_example_input() {
-        let games = Game::parse_games("../input/2_1_example_input.txt");
+        let games = Game::parse_games(&Input::new(EXAMPLE_INPUT)).unwrap();
 
         assert_eq!(games.len(), 5);
 
@@ -20,12 +25,14 @@ mod tests {
     }
 }
 
-pub fn run_task() {
-    let games = Game::parse_games("input/2_1_input.txt");
-
+pub fn solve(games: &Vec<Game>) -> u32 {
     let games_power: Vec<u32> = games.iter().map(|g| g.calculate_min_bag().power()).collect();
-    
-    let power_sum: u32 = games_power.iter().sum();
 
-    println!("Power sum is {}", power_sum);
-}
\ No newline at end of file
+    return games_power.iter().sum();
+}
+
+pub fn run_task(file: &str) {
+    let day = Day2::parse_file(file).unwrap();
+
+    println!("Power sum is {}", day.part2());
+}
@@ -1,5 +1,5 @@
-use std::fs::read_to_string;
 use regex::Regex;
+use aoc_helper::{Input, Line, ParseError};
 
 #[derive(Debug)]
 pub struct GameSet {
@@ -19,30 +19,30 @@ impl GameSet {
         GameSet { red: 0, green: 0, blue: 0 }
     }
 
-    pub fn parse(line: &str) -> GameSet {
+    pub fn parse(line: &Line, set: &str) -> Result<GameSet, ParseError> {
         let mut game_set = GameSet::empty_set();
     
         // Separate colors 
-        let colors: Vec<&str> = line.split(",").collect();
+        let colors: Vec<&str> = set.split(",").collect();
         for color in colors.iter() {
             // Separate count and color
             let color_split: Vec<&str> = color.trim().split(" ").collect();
     
             if color_split.len() != 2 {
-                panic!();
+                return Err(line.error(color, "<count> <color>"));
             }
     
-            let count: u32 = color_split[0].parse().unwrap();
+            let count: u32 = line.parse(color_split[0], "a cube count")?;
             
             match color_split[1] {
                 "red" => game_set.red = count,
                 "green" => game_set.green = count,
                 "blue" => game_set.blue = count,
-                _ => panic!(),
+                other => return Err(line.error(other, "red, green or blue")),
             }
         }
     
-        return game_set;
+        return Ok(game_set);
     }
 
 
@@ -70,43 +70,43 @@ impl Game {
         return bag
     }
 
-    fn parse_game_sets(line: &str) -> Vec<GameSet> {
+    fn parse_game_sets(line: &Line) -> Result<Vec<GameSet>, ParseError> {
         let re = Regex::new(r"Game [0-9]+: (.*)$").unwrap();
     
-        for (_, [game_sets_line]) in re.captures_iter(line).map(|c| c.extract()) {
+        for (_, [game_sets_line]) in re.captures_iter(line.text).map(|c| c.extract()) {
            let game_sets_split: Vec<&str> = game_sets_line.split(";").collect();
     
-           return game_sets_split.iter().map(|line| GameSet::parse(line)).collect();
+           return game_sets_split.iter().map(|set| GameSet::parse(line, set)).collect();
         }
     
-        panic!();
+        return Err(line.error(line.text, "Game <id>: <sets>"));
     }
     
-    fn parse_game_id(line: &str) -> u32 {
+    fn parse_game_id(line: &Line) -> Result<u32, ParseError> {
         let re = Regex::new(r"Game ([0-9]+): .*$").unwrap();
     
-        for (_, [game_id]) in re.captures_iter(line).map(|c| c.extract()) {
-            return game_id.parse::<u32>().unwrap();
+        for (_, [game_id]) in re.captures_iter(line.text).map(|c| c.extract()) {
+            return line.parse(game_id, "a game id");
         }
     
-        panic!();
+        return Err(line.error(line.text, "Game <id>: <sets>"));
     }
     
-    fn parse_game(line: &str) -> Game {
-        let game_id = Self::parse_game_id(line);
-        let game_sets = Self::parse_game_sets(line);
+    fn parse_game(line: &Line) -> Result<Game, ParseError> {
+        let game_id = Self::parse_game_id(line)?;
+        let game_sets = Self::parse_game_sets(line)?;
     
-        return Game { id: game_id, sets: game_sets };
+        return Ok(Game { id: game_id, sets: game_sets });
     }
     
-    pub fn parse_games(file: &str) -> Vec<Game> {
+    pub fn parse_games(input: &Input) -> Result<Vec<Game>, ParseError> {
         let mut games: Vec<Game> = Vec::new();
     
-        for line in read_to_string(file).unwrap().lines() {
-            games.push(Self::parse_game(line));
+        for line in input.lines() {
+            games.push(Self::parse_game(&line)?);
         }
     
-        return games;
+        return Ok(games);
     }
 
     pub fn is_possible(&self, bag: &GameSet) -> bool {
@@ -0,0 +1,26 @@
+pub mod task1;
+pub mod task2;
+pub mod trace;
+pub mod utils;
+
+use aoc_helper::{Answer, Input, ParseError, Solver};
+
+use crate::utils::ElectronicMap;
+
+pub struct Day20 {
+    map: ElectronicMap
+}
+
+impl Solver for Day20 {
+    fn parse_input(input: &Input) -> Result<Self, ParseError> {
+        return Ok(Day20 { map: ElectronicMap::parse(input)? });
+    }
+
+    fn part1(&self) -> Answer {
+        return task1::solve(self.map.clone()).into();
+    }
+
+    fn part2(&self) -> Answer {
+        return task2::solve(self.map.clone()).into();
+    }
+}
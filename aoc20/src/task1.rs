@@ -1,16 +1,36 @@
+use aoc_helper::Solver;
+
+use crate::Day20;
 use crate::utils::ElectronicMap;
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_helper::Input;
+
+    const EXAMPLE_INPUT_1: &str = "\
+broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a
+";
+
+    const EXAMPLE_INPUT_2: &str = "\
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output
+";
 
     #[test]
     fn test_example_input() {
         let button_presses = 1000;
 
         // Example 1
-        let mut map_1 = ElectronicMap::parse("../input/20_1_example_input_1.txt");
+        let mut map_1 = ElectronicMap::parse(&Input::new(EXAMPLE_INPUT_1)).unwrap();
         let (low_pulses_1, high_pulses_1) = map_1.determine_pulses_sent(button_presses);
         assert_eq!((low_pulses_1, high_pulses_1), (8000, 4000));
 
@@ -18,21 +38,45 @@ mod tests {
         assert_eq!(res_1, 32000000);
 
         // Example 2
-        let mut map_2 = ElectronicMap::parse("../input/20_1_example_input_2.txt");
+        let mut map_2 = ElectronicMap::parse(&Input::new(EXAMPLE_INPUT_2)).unwrap();
         let (low_pulses_2, high_pulses_2) = map_2.determine_pulses_sent(button_presses);
         assert_eq!((low_pulses_2, high_pulses_2), (4250, 2750));
 
         let res_2 = low_pulses_2 * high_pulses_2;
         assert_eq!(res_2, 11687500);
     }
+
+    #[test]
+    fn test_to_dot() {
+        let map = ElectronicMap::parse(&Input::new(EXAMPLE_INPUT_2)).unwrap();
+
+        assert_eq!(map.to_dot(), "\
+digraph \"modules\" {
+    \"a\" [shape=\"box\"];
+    \"b\" [shape=\"box\"];
+    \"broadcaster\" [shape=\"doublecircle\"];
+    \"con\" [shape=\"diamond\"];
+    \"inv\" [shape=\"diamond\"];
+    \"a\" -> \"inv\";
+    \"a\" -> \"con\";
+    \"b\" -> \"con\";
+    \"broadcaster\" -> \"a\";
+    \"con\" -> \"output\";
+    \"inv\" -> \"b\";
+}
+");
+    }
 }
 
-pub fn run_task() {
+pub fn solve(mut map: ElectronicMap) -> u64 {
     let button_presses = 1000;
-
-    let mut map = ElectronicMap::parse("input/20_1_input.txt");
     let (low_pulses, high_pulses) = map.determine_pulses_sent(button_presses);
 
-    let res = low_pulses * high_pulses;
-    println!("Res is: {}", res);
-}
\ No newline at end of file
+    return low_pulses * high_pulses;
+}
+
+pub fn run_task(file: &str) {
+    let day = Day20::parse_file(file).unwrap();
+
+    println!("Res is: {}", day.part1());
+}
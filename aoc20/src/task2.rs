@@ -1,15 +1,73 @@
+use aoc_helper::Solver;
+
+use crate::Day20;
 use crate::utils::ElectronicMap;
 
-pub fn run_task() {
-    //let button_presses = 100000000;
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use aoc_helper::{Answer, Input};
+
+    // Four counters sending a high pulse to zh every 9, 11, 13 and 15 presses
+    const COUNTERS_INPUT: &str = "\
+%ys -> bj
+&cc -> zh
+&aa -> zh
+%xa -> vy, bj
+&dd -> zh
+&bj -> xa, dd
+&pr -> ta, rh, bb
+%gw -> pr
+%ta -> pi, pr
+&qm -> rp, mu, cc
+%pu -> sc
+%pi -> rh, pr
+%lt -> pu
+broadcaster -> hs, ta, rp, xa
+%re -> lt
+&bb -> zh
+&sc -> hs, re, lt, aa
+%eh -> ue, qm
+%cf -> ys, bj
+%mu -> eh
+%rp -> mu, qm
+%vy -> cf, bj
+%ue -> qm
+%rh -> gw
+%hs -> re, sc
+&zh -> rx
+";
+
+    #[test]
+    fn test_counters_input() {
+        let map = ElectronicMap::parse(&Input::new(COUNTERS_INPUT)).unwrap();
+
+        assert_eq!(map.buttons_press_required_for_low_rx(), Ok(9 * 11 * 13 * 5));
+    }
+
+    #[test]
+    fn test_unsolvable_networks() {
+        // No module sends to rx
+        let day = Day20::parse("broadcaster -> a\n%a -> b\n&b -> output\n").unwrap();
+        assert_eq!(day.part2(), Answer::Unsolved);
+
+        let map = ElectronicMap::parse(&Input::new("broadcaster -> a\n%a -> rx\n")).unwrap();
+        assert_eq!(map.buttons_press_required_for_low_rx(), Err(String::from("Module a sending to rx is not a conjunction with inputs")));
 
-    let mut map = ElectronicMap::parse("input/20_1_input.txt");
+        // c sends a high pulse in both presses of its cycle
+        let map = ElectronicMap::parse(&Input::new("broadcaster -> a, b\n%a -> c\n%b -> c\n&c -> zh\n&zh -> rx\n")).unwrap();
+        assert_eq!(map.buttons_press_required_for_low_rx(), Err(String::from("Module c sends 2 high pulses per cycle of 2 presses, expected one")));
+    }
+}
 
-    let presses_required_rx = map.buttons_press_required_for_low_rx();
-    println!("Presses required: {}", presses_required_rx);
+pub fn solve(map: ElectronicMap) -> Option<u64> {
+    // Note: Only networks built like the puzzle inputs can be solved, see `buttons_press_required_for_low_rx`
+    return map.buttons_press_required_for_low_rx().ok();
+}
 
-    // let (low_pulses, high_pulses) = map.determine_pulses_sent(button_presses);
+pub fn run_task(file: &str) {
+    let day = Day20::parse_file(file).unwrap();
 
-    // let res = low_pulses * high_pulses;
-    // println!("Res is: {}", res);
-}
\ No newline at end of file
+    println!("Presses required: {}", day.part2());
+}
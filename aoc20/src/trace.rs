@@ -0,0 +1,210 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::utils::Pulse;
+
+/// A pulse sent during a button press
+#[derive(Debug, Clone, PartialEq)]
+pub struct PulseEvent {
+    pub press: u64,
+    /// Position of the pulse among all pulses of the press, in the order they are processed
+    pub index: usize,
+    pub sender: String,
+    pub receiver: String,
+    pub pulse: Pulse
+}
+
+/// Which pulses a trace records, an empty list of modules matches every module
+#[derive(Debug, Clone, Default)]
+pub struct TraceFilter {
+    pub senders: Vec<String>,
+    pub receivers: Vec<String>,
+    pub pulse: Option<Pulse>
+}
+
+/// Pulses recorded while pressing the button, see [`crate::utils::ElectronicMap::trace`]
+#[derive(Debug, Clone, Default)]
+pub struct Trace {
+    pub filter: TraceFilter,
+    pub events: Vec<PulseEvent>
+}
+
+impl TraceFilter {
+    pub fn matches(&self, sender: &str, receiver: &str, pulse: &Pulse) -> bool {
+        return (self.senders.is_empty() || self.senders.iter().any(|name| name == sender))
+            && (self.receivers.is_empty() || self.receivers.iter().any(|name| name == receiver))
+            && self.pulse.as_ref().is_none_or(|filter_pulse| filter_pulse == pulse);
+    }
+}
+
+impl Trace {
+    pub fn new(filter: TraceFilter) -> Trace {
+        return Trace { filter: filter, events: Vec::new() };
+    }
+
+    /// Record a pulse if it matches the filter
+    pub fn record(&mut self, press: u64, index: usize, sender: &str, receiver: &str, pulse: &Pulse) {
+        if self.filter.matches(sender, receiver, pulse) {
+            self.events.push(PulseEvent { press: press, index: index, sender: sender.to_owned(), receiver: receiver.to_owned(), pulse: pulse.clone() });
+        }
+    }
+
+    /// One line per pulse, with a header line
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("press,index,sender,receiver,pulse\n");
+        for event in self.events.iter() {
+            writeln!(csv, "{},{},{},{},{}", event.press, event.index, event.sender, event.receiver, pulse_name(&event.pulse)).unwrap();
+        }
+
+        return csv;
+    }
+
+    /// Value Change Dump for waveform viewers, with a wire per sender that is high while its last pulse was high
+    ///
+    /// Every pulse takes one time unit, a press starts every `1 + <most pulses in a press>` units so presses line up
+    pub fn to_vcd(&self) -> String {
+        let press_length = self.events.iter().map(|event| event.index + 1).max().unwrap_or(0) + 1;
+
+        // Note: Sorted so the output doesn't depend on the order modules are first seen in
+        let mut wires: BTreeMap<&str, (String, bool)> = BTreeMap::new();
+        for event in self.events.iter() {
+            wires.entry(&event.sender).or_insert((String::new(), false));
+        }
+        for (i, (_, (code, _))) in wires.iter_mut().enumerate() {
+            *code = vcd_code(i);
+        }
+
+        let mut vcd = String::from("$timescale 1ns $end\n$scope module network $end\n");
+        for (name, (code, _)) in wires.iter() {
+            writeln!(vcd, "$var wire 1 {} {} $end", code, name).unwrap();
+        }
+        vcd.push_str("$upscope $end\n$enddefinitions $end\n#0\n$dumpvars\n");
+        for (code, _) in wires.values() {
+            writeln!(vcd, "0{}", code).unwrap();
+        }
+        vcd.push_str("$end\n");
+
+        let mut time = 0;
+        for event in self.events.iter() {
+            let (code, high) = wires.get_mut(event.sender.as_str()).unwrap();
+            let event_high = event.pulse == Pulse::High;
+            if *high == event_high {
+                continue;
+            }
+            *high = event_high;
+
+            let event_time = event.press * press_length as u64 + event.index as u64;
+            if event_time != time {
+                writeln!(vcd, "#{}", event_time).unwrap();
+                time = event_time;
+            }
+            writeln!(vcd, "{}{}", if event_high { 1 } else { 0 }, code).unwrap();
+        }
+
+        return vcd;
+    }
+}
+
+fn pulse_name(pulse: &Pulse) -> &'static str {
+    return match pulse {
+        Pulse::Low => "low",
+        Pulse::High => "high"
+    };
+}
+
+// Identifier of the i-th wire, VCD identifiers are made of the printable ASCII characters '!' to '~'
+fn vcd_code(mut i: usize) -> String {
+    let mut code = String::new();
+    loop {
+        code.push((b'!' + (i % 94) as u8) as char);
+        i /= 94;
+        if i == 0 {
+            return code;
+        }
+        i -= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_helper::Input;
+
+    use super::*;
+    use crate::utils::ElectronicMap;
+
+    const EXAMPLE_INPUT_2: &str = "\
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output
+";
+
+    #[test]
+    fn test_csv() {
+        let mut map = ElectronicMap::parse(&Input::new(EXAMPLE_INPUT_2)).unwrap();
+        let trace = map.trace(1, TraceFilter::default());
+
+        assert_eq!(trace.to_csv(), "\
+press,index,sender,receiver,pulse
+1,0,button,broadcaster,low
+1,1,broadcaster,a,low
+1,2,a,inv,high
+1,3,a,con,high
+1,4,inv,b,low
+1,5,con,output,high
+1,6,b,con,high
+1,7,con,output,low
+");
+    }
+
+    #[test]
+    fn test_filter() {
+        let mut map = ElectronicMap::parse(&Input::new(EXAMPLE_INPUT_2)).unwrap();
+        let filter = TraceFilter { senders: vec![String::from("con")], receivers: Vec::new(), pulse: Some(Pulse::Low) };
+        let trace = map.trace(4, filter);
+
+        let presses: Vec<u64> = trace.events.iter().map(|event| event.press).collect();
+        assert_eq!(presses, vec![1, 3]);
+        assert!(trace.events.iter().all(|event| event.sender == "con" && event.receiver == "output"));
+    }
+
+    #[test]
+    fn test_vcd() {
+        let mut map = ElectronicMap::parse(&Input::new(EXAMPLE_INPUT_2)).unwrap();
+        let filter = TraceFilter { senders: vec![String::from("a"), String::from("con")], ..TraceFilter::default() };
+        let trace = map.trace(2, filter);
+
+        assert_eq!(trace.to_vcd(), "\
+$timescale 1ns $end
+$scope module network $end
+$var wire 1 ! a $end
+$var wire 1 \" con $end
+$upscope $end
+$enddefinitions $end
+#0
+$dumpvars
+0!
+0\"
+$end
+#11
+1!
+#14
+1\"
+#16
+0\"
+#20
+0!
+#23
+1\"
+");
+    }
+
+    #[test]
+    fn test_vcd_codes() {
+        assert_eq!(vcd_code(0), "!");
+        assert_eq!(vcd_code(93), "~");
+        assert_eq!(vcd_code(94), "!!");
+        assert_eq!(vcd_code(94 + 94 * 94), "!!!");
+    }
+}
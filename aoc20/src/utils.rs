@@ -1,5 +1,12 @@
 use std::collections::{HashMap, VecDeque, HashSet};
 
+use aoc_helper::{Input, Line, ParseError};
+use aoc_helper::cycle::{self, Cycle};
+use aoc_helper::dot::Dot;
+use aoc_helper::math;
+
+use crate::trace::{Trace, TraceFilter};
+
 // FlipFlop
 // on/off
 // high pulse -> nothing
@@ -19,12 +26,12 @@ use std::collections::{HashMap, VecDeque, HashSet};
 // Pulses are processed in the order they are sent
 
 #[derive(Clone, Debug, PartialEq)]
-enum Pulse {
+pub enum Pulse {
     Low,
     High
 }
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
 enum ModuleType {
     FlipFlop(bool), // Bool for on/off (true = on, false = off)
     Conjunction(HashMap<String, Pulse>), // Map node name to most recent type of input received (false = low, true = high)
@@ -32,20 +39,14 @@ enum ModuleType {
     //Button
 }
 
-#[derive(Debug)]
-struct Observe {
-    name: String,
-    pulse: Pulse,
-    observations: Vec<u64>
-}
-
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
 struct ElectronicModule {
     name: String,
     module_type: ModuleType,
     destinations: Vec<String>
 }
 
+#[derive(Clone, PartialEq)]
 pub struct ElectronicMap {
     modules: HashMap<String, ElectronicModule>
 }
@@ -94,11 +95,11 @@ impl ElectronicModule {
         }
     }
 
-    fn find_sources(name: &str, lines: &Vec<String>) -> Vec<String> {
+    fn find_sources(name: &str, lines: &[Line]) -> Vec<String> {
         let mut sources = Vec::new();
 
         for line in lines.iter() {
-            let mut split_line: std::str::Split<'_, &str> = line.split(" -> ");
+            let mut split_line: std::str::Split<'_, &str> = line.text.split(" -> ");
             let left_side = split_line.next().unwrap();
             let right_side = split_line.last().unwrap();
             right_side.split(", ").for_each(|dest| {
@@ -112,8 +113,8 @@ impl ElectronicModule {
         return sources;
     }
 
-    fn parse(line: &str, lines: &Vec<String>) -> ElectronicModule {
-        let mut split_line = line.split(" -> ");
+    fn parse(line: &Line, lines: &[Line]) -> Result<ElectronicModule, ParseError> {
+        let mut split_line = line.text.split(" -> ");
         let left_side = split_line.next().unwrap();
 
         let name: String;
@@ -131,13 +132,13 @@ impl ElectronicModule {
             name = String::from("broadcaster");
             module_type = ModuleType::Broadcast;
         } else {
-            panic!("Unexpected left side: {}", left_side);
+            return Err(line.error(left_side, "%<name>, &<name> or broadcaster"));
         }
 
-        let right_side = split_line.next().unwrap();
+        let right_side = split_line.next().ok_or_else(|| line.end("' -> ' followed by destinations"))?;
         let destinations: Vec<String> = right_side.split(", ").map(|dest| dest.to_string()).collect();
 
-        ElectronicModule { name: name, module_type: module_type, destinations: destinations }
+        Ok(ElectronicModule { name: name, module_type: module_type, destinations: destinations })
 
     }
 }
@@ -170,7 +171,7 @@ impl ElectronicMap {
         ElectronicMap { modules: modules }
     }
 
-    fn press_button(&mut self, press_i: u64, mut observe: Option<&mut Observe>) -> (u64, u64) {
+    fn press_button(&mut self, press_i: u64, mut trace: Option<&mut Trace>) -> (u64, u64) {
         let mut queue = VecDeque::new();
         let mut low_pulses = 0;
         let mut high_pulses = 0;
@@ -179,6 +180,10 @@ impl ElectronicMap {
         queue.push_back((String::from("broadcaster"), String::from("button"), Pulse::Low));
 
         while let Some((receiver_str, sender_str, pulse)) = queue.pop_front() {
+            if let Some(trace) = trace.as_deref_mut() {
+                trace.record(press_i, (low_pulses + high_pulses) as usize, &sender_str, &receiver_str, &pulse);
+            }
+
             match pulse {
                 Pulse::Low => low_pulses += 1,
                 Pulse::High => high_pulses += 1
@@ -186,15 +191,6 @@ impl ElectronicMap {
 
             let receiver = self.modules.get_mut(&receiver_str);
             if receiver.is_none() { // E.g. for virtual "output" node
-                if observe.is_some() {
-                    let observe_unwrapped = observe.unwrap();
-
-                    if sender_str == observe_unwrapped.name && pulse == observe_unwrapped.pulse {
-                        observe_unwrapped.observations.push(press_i);
-                    }
-
-                    observe = Some(observe_unwrapped); // Ugly!
-                }
                 continue;
             }
 
@@ -205,43 +201,73 @@ impl ElectronicMap {
         return (low_pulses, high_pulses);
     }
 
-    fn observe(&mut self, module_name: &str, pulse: Pulse) {
-        let mut observe = Observe { name: module_name.to_owned(), pulse: pulse, observations: Vec::new() };
-        let button_presses = 20000;
-
+    /// Press the button `button_presses` times, recording the pulses that match `filter`
+    pub fn trace(&mut self, button_presses: u64, filter: TraceFilter) -> Trace {
+        let mut trace = Trace::new(filter);
         for i in 1..(button_presses + 1) {
-            let _ = self.press_button(i, Some(&mut observe));
+            let _ = self.press_button(i, Some(&mut trace));
         }
 
-        println!("Observed: {:?}", observe);
-        let diffs: Vec<u64> = observe.observations.iter().enumerate().map(|(i, val)| {
-            if i == 0 {
-                *val
-            } else {
-                *val - observe.observations[i - 1]
-            }
-        }).collect();
-        println!("Diffs: {:?}", diffs);
+        return trace;
+    }
+
+    // Presses where `module_name` sends `pulse`, until the state of the map repeats
+    fn observe(&self, module_name: &str, pulse: Pulse) -> (Cycle, Vec<u64>) {
+        let cycle = cycle::brent(self, |map| {
+            let mut next = map.clone();
+            next.press_button(0, None);
+            next
+        });
+
+        let filter = TraceFilter { senders: vec![module_name.to_owned()], receivers: Vec::new(), pulse: Some(pulse) };
+        let trace = self.clone().trace((cycle.prefix + cycle.length) as u64, filter);
+
+        let mut presses: Vec<u64> = trace.events.iter().map(|event| event.press).collect();
+        presses.dedup();
+
+        return (cycle, presses);
     }
 
-    pub fn buttons_press_required_for_low_rx(&mut self) -> u64 {
-        // In input, only "&zh -> rx" can send to rx
-        // &zh depends on &vd, &ns, &bh, &dl (which in turn depend on four large &??)
-        
-        // TODO: Find nodes dependent on zh (manual for now :D)
-        let zh_senders = vec!["vd", "ns", "bh", "dl"];
+    /// Button presses until rx receives a low pulse
+    ///
+    /// Only a single conjunction sends to rx, so all of its inputs must have sent a high pulse last. Each input is the
+    /// end of a separate part of the network, in which it sends a high pulse once per cycle of that part's states.
+    /// Assumes these high pulses line up within the button press where all of them happen. Gives an error for networks
+    /// that are not built like this, such as the part 1 examples
+    pub fn buttons_press_required_for_low_rx(&self) -> Result<u64, String> {
+        let rx_senders: Vec<&ElectronicModule> = self.modules.values().filter(|module| module.destinations.iter().any(|dest| dest == "rx")).collect();
+        if rx_senders.len() != 1 {
+            return Err(format!("Expected a single module sending to rx, found {}", rx_senders.len()));
+        }
 
-        // Split these nodes into sub-electronic maps
-        let mut sub_maps: Vec<ElectronicMap> = zh_senders.iter().map(|sender| self.create_sub_map(sender)).collect();
+        let inputs: Vec<&String> = match &rx_senders[0].module_type {
+            ModuleType::Conjunction(memory) if !memory.is_empty() => memory.keys().collect(),
+            _ => return Err(format!("Module {} sending to rx is not a conjunction with inputs", rx_senders[0].name))
+        };
 
-        // Observe when all these nodes send "high pulses" and find a common index
-        sub_maps.iter_mut().enumerate().for_each(|(i, map)| map.observe(zh_senders[i], Pulse::High));
+        // Split the inputs into sub-electronic maps, and observe when they send "high pulses" during one cycle
+        let congruences = inputs.iter().map(|input| {
+            let (cycle, presses) = self.create_sub_map(input).observe(input, Pulse::High);
+
+            // Note: Presses before the cycle starts don't repeat, so they are ignored
+            let repeating: Vec<u64> = presses.into_iter().filter(|press| *press > cycle.prefix as u64).collect();
+            if repeating.len() != 1 {
+                return Err(format!("Module {} sends {} high pulses per cycle of {} presses, expected one", input, repeating.len(), cycle.length));
+            }
 
-        // In the observations printed in map.observe, we can see that there is a pattern for when each of the four nodes sends "high pulse"
-        // They all occur every x_i button press, where all x_i are prime numbers, so just multiply them together to get the answer
-        // Did this part manually :D
+            Ok((repeating[0] as i128, cycle.length as i128))
+        }).collect::<Result<Vec<_>, String>>()?;
 
-        return 0; // TODO: Change
+        let first_press = congruences.iter().map(|(press, _)| *press).max().unwrap_or(1);
+        let (press, period) = match math::crt(congruences) {
+            Ok(solution) => solution,
+            Err(e) => return Err(format!("The inputs of {} never all send high pulses in the same press: {}", rx_senders[0].name, e))
+        };
+
+        // The solution must be after every input sent its first high pulse
+        let behind = (first_press - press).max(0);
+
+        return Ok((press + (behind + period - 1) / period * period) as u64);
     }
 
     pub fn determine_pulses_sent(&mut self, button_presses: u64) -> (u64, u64) {
@@ -259,15 +285,45 @@ impl ElectronicMap {
         return (low_pulses, high_pulses);
     }
 
-    pub fn parse(file: &str) -> ElectronicMap {
-        let lines = aoc_helper::read_lines(file);
+    /// The network in the DOT language of Graphviz, with flip-flops as boxes, conjunctions as diamonds and the
+    /// broadcaster as a double circle
+    pub fn to_dot(&self) -> String {
+        let mut dot = Dot::directed("modules");
+
+        let mut names: Vec<&String> = self.modules.keys().collect();
+        names.sort();
+
+        for name in names.iter() {
+            let shape = match self.modules[*name].module_type {
+                ModuleType::FlipFlop(_) => "box",
+                ModuleType::Conjunction(_) => "diamond",
+                ModuleType::Broadcast => "doublecircle"
+            };
+            dot.node(name, &[("shape", shape)]);
+        }
+
+        for name in names {
+            for destination in self.modules[name].destinations.iter() {
+                dot.edge(name, destination, &[]);
+            }
+        }
+
+        return dot.finish();
+    }
+
+    pub fn parse(input: &Input) -> Result<ElectronicMap, ParseError> {
+        let lines = input.lines();
 
         let mut modules = HashMap::new();
         for line in lines.iter() {
-            let module = ElectronicModule::parse(line, &lines);
+            let module = ElectronicModule::parse(line, &lines)?;
             modules.insert(module.name.clone(), module);
         }
 
-        ElectronicMap { modules: modules }
+        if !modules.contains_key("broadcaster") {
+            return Err(input.end("a broadcaster module"));
+        }
+
+        Ok(ElectronicMap { modules: modules })
     }
 }
\ No newline at end of file
@@ -0,0 +1,25 @@
+pub mod task1;
+pub mod task2;
+pub mod utils;
+
+use aoc_helper::{Answer, Input, ParseError, Solver};
+
+use crate::utils::FarmMap;
+
+pub struct Day21 {
+    farm_map: FarmMap
+}
+
+impl Solver for Day21 {
+    fn parse_input(input: &Input) -> Result<Self, ParseError> {
+        return Ok(Day21 { farm_map: FarmMap::parse(input)? });
+    }
+
+    fn part1(&self) -> Answer {
+        return task1::solve(&self.farm_map).into();
+    }
+
+    fn part2(&self) -> Answer {
+        return task2::solve(&self.farm_map).into();
+    }
+}
@@ -1,23 +1,45 @@
+use aoc_helper::Solver;
+
+use crate::Day21;
 use crate::utils::FarmMap;
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_helper::Input;
+
+    const EXAMPLE_INPUT: &str = "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+";
 
     #[test]
     fn test_example_input() {
-        let farm_map = FarmMap::parse("../input/21_1_example_input.txt");
+        let farm_map = FarmMap::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
         let garden_plots_reached = farm_map.calculate_garden_plots_reached(6, false);
 
         assert_eq!(garden_plots_reached, 16);
     }
 }
 
-pub fn run_task() {
-    let farm_map = FarmMap::parse("input/21_1_input.txt");
-    let steps = 64;
-    let garden_plots_reached = farm_map.calculate_garden_plots_reached(steps, false);
+const STEPS: u64 = 64;
+
+pub fn solve(farm_map: &FarmMap) -> u64 {
+    return farm_map.calculate_garden_plots_reached(STEPS, false);
+}
+
+pub fn run_task(file: &str) {
+    let day = Day21::parse_file(file).unwrap();
 
-    println!("Garden plots reached after {} steps: {}", steps, garden_plots_reached);
-}
\ No newline at end of file
+    println!("Garden plots reached after {} steps: {}", STEPS, day.part1());
+}
@@ -1,10 +1,17 @@
+use aoc_helper::Solver;
+
+use crate::Day21;
 use crate::utils::FarmMap;
 
 // Test input and real input does not share the same properties
-pub fn run_task() {
-    let farm_map = FarmMap::parse("input/21_1_input.txt");
-    let steps = 26501365;
-    let garden_plots_reached = farm_map.calculate_garden_plots_reached_opt(steps);
+const STEPS: u64 = 26501365;
+
+pub fn solve(farm_map: &FarmMap) -> u64 {
+    return farm_map.calculate_garden_plots_reached_opt(STEPS);
+}
+
+pub fn run_task(file: &str) {
+    let day = Day21::parse_file(file).unwrap();
 
-    println!("Garden plots reached in {} steps: {}", steps, garden_plots_reached);
-}
\ No newline at end of file
+    println!("Garden plots reached in {} steps: {}", STEPS, day.part2());
+}
@@ -1,7 +1,10 @@
 use std::collections::{HashSet, VecDeque};
 
+use aoc_helper::{Input, ParseError};
+use aoc_helper::grid::Grid;
+
 pub struct FarmMap {
-    map: Vec<Vec<char>>
+    map: Grid<char>
 }
 
 impl FarmMap {
@@ -19,29 +22,15 @@ impl FarmMap {
         return sum as u64;
     }
 
-    fn find_start_position(map: &Vec<Vec<char>>) -> (isize, isize) {
-        for (y, line) in map.iter().enumerate() {
-            for (x, c) in line.iter().enumerate() {
-                if *c == 'S' {
-                    return (y as isize, x as isize);
-                }
-            }
-        } 
-        panic!("Could not find start position");
-    }
-
-    fn positive_modulo(a: isize, b: usize) -> usize {
-        let mut res = a % (b as isize);
-        if res < 0 {
-            res += b as isize;
-        }
+    fn find_start_position(map: &Grid<char>) -> (isize, isize) {
+        let (x, y) = map.position(|c| *c == 'S').expect("Could not find start position");
 
-        return res as usize;
+        return (y as isize, x as isize);
     }
 
-    fn move_pos(map: &Vec<Vec<char>>, pos: &(isize, isize), step: u64, unique_plots_reached: &Vec<HashSet<(isize, isize)>>, next_queue: &mut VecDeque<(isize, isize)>, infinite: bool) {
-        let height = map.len();
-        let width = map.first().unwrap().len();
+    fn move_pos(map: &Grid<char>, pos: &(isize, isize), step: u64, unique_plots_reached: &Vec<HashSet<(isize, isize)>>, next_queue: &mut VecDeque<(isize, isize)>, infinite: bool) {
+        let height = map.height();
+        let width = map.width();
 
         let mut positions_to_test = Vec::new();
 
@@ -68,7 +57,7 @@ impl FarmMap {
         // Check for rocks
         for i in (0..positions_to_test.len()).rev() {
             let tmp_pos = positions_to_test[i];
-            if map[Self::positive_modulo(tmp_pos.0, height)][Self::positive_modulo(tmp_pos.1, width)] == '#' {
+            if *map.get_wrapping(tmp_pos.1, tmp_pos.0) == '#' {
                 positions_to_test.remove(i);
             }
         }
@@ -122,7 +111,7 @@ impl FarmMap {
     }
 
     pub fn calculate_garden_plots_reached_opt(&self, steps: u64) -> u64 {
-        let map_len = self.map.len() as u64;
+        let map_len = self.map.height() as u64;
         let half_map_len = map_len / 2 as u64;
 
         // Reachable tiles (ignoring rocks) (2n + 1)^2 / 2
@@ -159,10 +148,13 @@ impl FarmMap {
         return Self::calculate_unique_pos(steps, &unique_plots_reached);
     }
 
-    pub fn parse(file: &str) -> FarmMap {
-        let lines = aoc_helper::read_lines(file);
-        let map: Vec<Vec<char>> = lines.into_iter().map(|line| line.chars().collect()).collect();
+    pub fn parse(input: &Input) -> Result<FarmMap, ParseError> {
+        let map = Grid::parse(input, ".#S")?;
+
+        if !map.values().any(|&c| c == 'S') {
+            return Err(input.end("a starting position 'S'"));
+        }
 
-        FarmMap { map: map }
+        Ok(FarmMap { map: map })
     }
 }
\ No newline at end of file
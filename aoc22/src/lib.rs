@@ -0,0 +1,36 @@
+pub mod task1;
+pub mod task2;
+pub mod utils;
+
+use aoc_helper::{Answer, Input, ParseError, Solver};
+
+use crate::utils::BrickSnapshot;
+
+pub struct Day22 {
+    snapshot: BrickSnapshot
+}
+
+impl Solver for Day22 {
+    fn parse_input(input: &Input) -> Result<Self, ParseError> {
+        return Ok(Day22 { snapshot: BrickSnapshot::parse(input)? });
+    }
+
+    fn part1(&self) -> Answer {
+        return task1::solve(self.snapshot.clone()).into();
+    }
+
+    fn part2(&self) -> Answer {
+        return task2::solve(self.snapshot.clone()).into();
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE_INPUT: &str = "\
+1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9
+";
@@ -1,22 +1,30 @@
+use aoc_helper::Solver;
+
+use crate::Day22;
 use crate::utils::BrickSnapshot;
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_example_input() {
-        let mut snapshot = BrickSnapshot::parse("../input/22_1_example_input.txt");
+        let mut snapshot = BrickSnapshot::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
         let fall_count = snapshot.determine_bricks_that_would_fall();
 
         assert_eq!(fall_count, 7);
     }
 }
 
-pub fn run_task() {
-    let mut snapshot = BrickSnapshot::parse("input/22_1_input.txt");
-    let fall_count = snapshot.determine_bricks_that_would_fall();
+pub fn solve(mut snapshot: BrickSnapshot) -> u64 {
+    return snapshot.determine_bricks_that_would_fall();
+}
+
+pub fn run_task(file: &str) {
+    let day = Day22::parse_file(file).unwrap();
 
-    println!("Fall count: {}", fall_count);
-}
\ No newline at end of file
+    println!("Fall count: {}", day.part1());
+}
@@ -1,22 +1,30 @@
+use aoc_helper::Solver;
+
+use crate::Day22;
 use crate::utils::BrickSnapshot;
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_example_input() {
-        let mut snapshot = BrickSnapshot::parse("../input/22_1_example_input.txt");
+        let mut snapshot = BrickSnapshot::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
         let disintegration_count = snapshot.determine_disintegration_count();
 
         assert_eq!(disintegration_count, 5);
     }
 }
 
-pub fn run_task() {
-    let mut snapshot = BrickSnapshot::parse("input/22_1_input.txt");
-    let disintegration_count = snapshot.determine_disintegration_count();
+pub fn solve(mut snapshot: BrickSnapshot) -> u64 {
+    return snapshot.determine_disintegration_count();
+}
+
+pub fn run_task(file: &str) {
+    let day = Day22::parse_file(file).unwrap();
 
-    println!("Disintegration count: {}", disintegration_count);
-}
\ No newline at end of file
+    println!("Disintegration count: {}", day.part2());
+}
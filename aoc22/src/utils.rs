@@ -3,6 +3,7 @@ use std::rc::Rc;
 use std::cell::RefCell;
 
 use regex::Regex;
+use aoc_helper::{Input, Line, ParseError};
 
 #[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
 struct Pos {
@@ -17,13 +18,14 @@ struct Brick {
     end: Pos
 }
 
+#[derive(Clone)]
 pub struct BrickSnapshot {
     bricks: Vec<Brick>
 }
 
 impl Pos {
-    fn from_strs(x: &str, y: &str, z: &str) -> Pos {
-        Pos { x: x.parse().unwrap(), y: y.parse().unwrap(), z: z.parse().unwrap() }
+    fn from_strs(line: &Line, x: &str, y: &str, z: &str) -> Result<Pos, ParseError> {
+        Ok(Pos { x: line.parse(x, "a coordinate")?, y: line.parse(y, "a coordinate")?, z: line.parse(z, "a coordinate")? })
     }
 }
 
@@ -52,16 +54,21 @@ impl Brick {
         return surface_coords;
     }
 
-    fn parse(line: &str) -> Brick {
-        let re = Regex::new(r"([0-9]+),([0-9]+),([0-9]+)~([0-9]+),([0-9]+),([0-9]+)").unwrap();
-        for (_, [sx, sy, sz, ex, ey, ez]) in re.captures_iter(line).map(|c| c.extract()) {
-            let start = Pos::from_strs(sx, sy, sz);
-            let end = Pos::from_strs(ex, ey, ez);
+    fn parse(line: &Line) -> Result<Brick, ParseError> {
+        let re = Regex::new(r"^([0-9]+),([0-9]+),([0-9]+)~([0-9]+),([0-9]+),([0-9]+)$").unwrap();
+        for (_, [sx, sy, sz, ex, ey, ez]) in re.captures_iter(line.text).map(|c| c.extract()) {
+            let start = Pos::from_strs(line, sx, sy, sz)?;
+            let end = Pos::from_strs(line, ex, ey, ez)?;
 
-            return Brick { start: start, end: end };
+            // The rest of the code assumes that the end is never before the start
+            if end.x < start.x || end.y < start.y || end.z < start.z {
+                return Err(line.error(line.text, "a brick where the end is not before the start"));
+            }
+
+            return Ok(Brick { start: start, end: end });
         }
 
-        panic!("Could not parse Brick from: {}", line);
+        return Err(line.error(line.text, "a brick (<x>,<y>,<z>~<x>,<y>,<z>)"));
     }
 }
 
@@ -228,10 +235,9 @@ impl BrickSnapshot {
         return Self::determine_disintegration_count_priv(&self.bricks, &dependencies);
     }
 
-    pub fn parse(file: &str) -> BrickSnapshot {
-        let lines = aoc_helper::read_lines(file);
-        let bricks = lines.iter().map(|line| Brick::parse(line)).collect();
+    pub fn parse(input: &Input) -> Result<BrickSnapshot, ParseError> {
+        let bricks = input.lines().iter().map(|line| Brick::parse(line)).collect::<Result<_, _>>()?;
 
-        BrickSnapshot { bricks: bricks }
+        Ok(BrickSnapshot { bricks: bricks })
     }
 }
\ No newline at end of file
@@ -0,0 +1,58 @@
+pub mod task1;
+pub mod task2;
+pub mod utils;
+pub mod utils2;
+
+use aoc_helper::{Answer, Input, ParseError, Solver};
+
+use crate::utils::TrailMap;
+use crate::utils2::TrailGraph;
+
+pub struct Day23 {
+    trail_map: TrailMap,
+    trail_graph: TrailGraph
+}
+
+impl Solver for Day23 {
+    fn parse_input(input: &Input) -> Result<Self, ParseError> {
+        return Ok(Day23 {
+            trail_map: TrailMap::parse(input)?,
+            trail_graph: TrailGraph::parse(input)?
+        });
+    }
+
+    fn part1(&self) -> Answer {
+        return task1::solve(self.trail_map.clone()).into();
+    }
+
+    fn part2(&self) -> Answer {
+        return task2::solve(&self.trail_graph).into();
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE_INPUT: &str = "\
+#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#
+";
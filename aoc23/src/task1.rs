@@ -1,22 +1,30 @@
+use aoc_helper::Solver;
+
+use crate::Day23;
 use crate::utils::TrailMap;
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_example_input() {
-        let mut trail_map = TrailMap::parse("../input/23_1_example_input.txt");
+        let mut trail_map = TrailMap::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
         let longest_hike = trail_map.find_longest_hike(false);
 
         assert_eq!(longest_hike, 94);
     }
 }
 
-pub fn run_task() {
-    let mut trail_map = TrailMap::parse("input/23_1_input.txt");
-    let longest_hike = trail_map.find_longest_hike(false);
+pub fn solve(mut trail_map: TrailMap) -> u64 {
+    return trail_map.find_longest_hike(false);
+}
+
+pub fn run_task(file: &str) {
+    let day = Day23::parse_file(file).unwrap();
 
-    println!("Longest hike: {}", longest_hike);
-}
\ No newline at end of file
+    println!("Longest hike: {}", day.part1());
+}
@@ -1,3 +1,6 @@
+use aoc_helper::Solver;
+
+use crate::Day23;
 use crate::utils2::TrailGraph;
 
 #[cfg(test)]
@@ -6,19 +9,36 @@ mod tests {
     use crate::utils2::TrailGraph;
 
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_example_input() {
-        let mut trail_graph = TrailGraph::parse("../input/23_1_example_input.txt");
+        let mut trail_graph = TrailGraph::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
         let longest_path = trail_graph.find_longest_path();
         
         assert_eq!(longest_path, 154);
     }
+
+    #[test]
+    fn test_to_dot() {
+        let trail_graph = TrailGraph::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
+        let dot = trail_graph.to_dot();
+
+        // 9 crossings (with start and end) and 12 trails between them
+        assert_eq!(dot.lines().filter(|line| line.contains(" -- ")).count(), 12);
+        assert!(dot.contains("    \"1,0\" [shape=\"doublecircle\"];\n"));
+        assert!(dot.contains("    \"1,0\" -- \"3,5\" [label=\"15\"];\n"));
+        assert!(dot.contains("    \"19,19\" -- \"21,22\" [label=\"5\"];\n"));
+    }
+}
+
+pub fn solve(trail_graph: &TrailGraph) -> u64 {
+    return trail_graph.find_longest_path();
 }
 
-pub fn run_task() {
-    let mut trail_graph = TrailGraph::parse("input/23_1_input.txt");
-    let longest_path = trail_graph.find_longest_path();
+pub fn run_task(file: &str) {
+    let day = Day23::parse_file(file).unwrap();
 
-    println!("Longest path is: {}", longest_path);
-}
\ No newline at end of file
+    println!("Longest path is: {}", day.part2());
+}
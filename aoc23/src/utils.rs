@@ -1,5 +1,9 @@
 use std::{rc::Rc, collections::VecDeque};
 
+use aoc_helper::{Input, ParseError};
+use aoc_helper::grid::Grid;
+
+#[derive(Clone)]
 pub enum SlopeType {
     North,
     West,
@@ -7,6 +11,7 @@ pub enum SlopeType {
     East
 }
 
+#[derive(Clone)]
 pub enum TileType {
     Path,
     Forest,
@@ -23,13 +28,15 @@ struct TestTile {
     previously_visited_pos: Vec<Rc<Vec<Pos>>>
 }
 
+#[derive(Clone)]
 pub struct Tile {
     pub tile_type: TileType,
     //highest_cost: u64
 }
 
+#[derive(Clone)]
 pub struct TrailMap {
-    pub map: Vec<Vec<Tile>>
+    pub map: Grid<Tile>
 }
 
 impl SlopeType {
@@ -78,7 +85,7 @@ impl Tile {
         let tile_type = match c {
             '.' => TileType::Path,
             '#' => TileType::Forest,
-            _ => TileType::Slope(SlopeType::parse(c).unwrap())
+            _ => TileType::Slope(SlopeType::parse(c).expect("Tile chars are validated when parsing the map"))
         };
 
         Tile { tile_type: tile_type }
@@ -98,24 +105,9 @@ impl TrailMap {
         return false;
     }
 
-    fn add_next_steps(test_tile: TestTile, map: &Vec<Vec<Tile>>, test_tile_queue: &mut VecDeque<TestTile>, ignore_slopes: bool) {
-        let height = map.len();
-        let width = map.first().unwrap().len();
-        let mut possible_next_pos = Vec::new();
-
+    fn add_next_steps(test_tile: TestTile, map: &Grid<Tile>, test_tile_queue: &mut VecDeque<TestTile>, ignore_slopes: bool) {
         let pos = &test_tile.pos;
-        if pos.0 > 0 {
-            possible_next_pos.push((pos.0 - 1, pos.1))
-        }
-        if pos.0 + 1 < height {
-            possible_next_pos.push((pos.0 + 1, pos.1))
-        }
-        if pos.1 > 0 {
-            possible_next_pos.push((pos.0, pos.1 - 1))
-        }
-        if pos.1 + 1 < width {
-            possible_next_pos.push((pos.0, pos.1 + 1))
-        }
+        let mut possible_next_pos: Vec<Pos> = map.neighbours4(pos.1, pos.0).map(|(x, y)| (y, x)).collect();
 
         for i in (0..possible_next_pos.len()).rev() {
             let next_pos = &possible_next_pos[i];
@@ -187,7 +179,7 @@ impl TrailMap {
         }
     }
 
-    fn move_to_tile(mut test_tile: TestTile, map: &mut Vec<Vec<Tile>>, test_tile_queue: &mut VecDeque<TestTile>, test_tile_finished: &mut (u64, TestTile), end_pos: &Pos, ignore_slopes: bool) {
+    fn move_to_tile(mut test_tile: TestTile, map: &mut Grid<Tile>, test_tile_queue: &mut VecDeque<TestTile>, test_tile_finished: &mut (u64, TestTile), end_pos: &Pos, ignore_slopes: bool) {
         // Add self to visited tiles
         test_tile.visited_pos.push(test_tile.pos);
 
@@ -217,9 +209,9 @@ impl TrailMap {
         Self::add_next_steps(test_tile, map, test_tile_queue, ignore_slopes);
     }
 
-    pub fn determine_start_and_end_tile_pos(map: &Vec<Vec<Tile>>) -> (Pos, Pos) {
+    pub fn determine_start_and_end_tile_pos(map: &Grid<Tile>) -> (Pos, Pos) {
         let mut start_pos = None;
-        for (x, tile) in map.first().unwrap().iter().enumerate() {
+        for (x, tile) in map.row(0).iter().enumerate() {
             match tile.tile_type {
                 TileType::Path => start_pos = Some((0, x)),
                 _ => continue
@@ -227,9 +219,9 @@ impl TrailMap {
         }
 
         let mut end_pos = None;
-        for (x, tile) in map.last().unwrap().iter().enumerate() {
+        for (x, tile) in map.row(map.height() - 1).iter().enumerate() {
             match tile.tile_type {
-                TileType::Path => end_pos = Some((map.len() - 1, x)),
+                TileType::Path => end_pos = Some((map.height() - 1, x)),
                 _ => continue
             }
         }
@@ -269,10 +261,17 @@ impl TrailMap {
         return max_cost - 1; // -1 to remove start tile step
     }
 
-    pub fn parse(file: &str) -> TrailMap {
-        let lines = aoc_helper::read_lines(file);
-        let map = lines.iter().map(|line| line.chars().map(|c| Tile::parse(c)).collect()).collect();
+    pub fn parse(input: &Input) -> Result<TrailMap, ParseError> {
+        let lines = input.lines();
+        let map = Grid::parse(input, ".#^<v>")?.map(|c| Tile::parse(*c));
+
+        // The hike starts and ends on a path in the first and last row
+        for line in [lines.first().unwrap(), lines.last().unwrap()] {
+            if !line.text.contains('.') {
+                return Err(line.error(line.text, "a row with a path tile"));
+            }
+        }
 
-        TrailMap { map: map }
+        Ok(TrailMap { map: map })
     }
 }
\ No newline at end of file
@@ -1,5 +1,9 @@
 use std::collections::{HashMap, VecDeque};
 
+use aoc_helper::{Input, ParseError};
+use aoc_helper::dot::Dot;
+use aoc_helper::grid::Grid;
+
 use crate::utils::{TrailMap, Tile, TileType};
 
 type Pos = (usize, usize);
@@ -130,7 +134,7 @@ impl TrailGraph {
         }
     }
 
-    fn create_node(map: &Vec<Vec<Tile>>, pos: Pos, nodes: &mut HashMap<Pos, Node>) {
+    fn create_node(map: &Grid<Tile>, pos: Pos, nodes: &mut HashMap<Pos, Node>) {
         let mut node = Node::new_empty(pos.clone());
 
         // Check tile type of node
@@ -192,28 +196,52 @@ impl TrailGraph {
         return highest_cost as u64;
     }
 
-    pub fn parse(file: &str) -> TrailGraph {
-        let trail_map = TrailMap::parse(file);
+    /// The compressed trails in the DOT language of Graphviz, with the steps between crossings as edge labels and the
+    /// start and end as double circles
+    pub fn to_dot(&self) -> String {
+        let mut dot = Dot::undirected("trails");
+        // Note: Positions are (row, column)
+        let id = |pos: &Pos| format!("{},{}", pos.1, pos.0);
+
+        let mut positions: Vec<&Pos> = self.nodes.keys().collect();
+        positions.sort();
+
+        for pos in [&self.start_pos, &self.end_pos] {
+            dot.node(&id(pos), &[("shape", "doublecircle")]);
+        }
+
+        for pos in positions {
+            let mut vertices: Vec<(&Pos, &usize)> = self.nodes[pos].vertices.iter().filter(|(other, _)| *other > pos).collect();
+            vertices.sort();
+
+            for (other, cost) in vertices {
+                dot.edge(&id(pos), &id(other), &[("label", &cost.to_string())]);
+            }
+        }
+
+        return dot.finish();
+    }
+
+    pub fn parse(input: &Input) -> Result<TrailGraph, ParseError> {
+        let trail_map = TrailMap::parse(input)?;
         let mut nodes = HashMap::new();
 
-        for (y, line_vec) in trail_map.map.iter().enumerate() {
-            for (x, tile) in line_vec.iter().enumerate() {
-                match tile.tile_type {
-                    TileType::Forest => continue,
-                    _ => {
-                        let pos = (y, x);
-                        Self::create_node(&trail_map.map, pos, &mut nodes);
-                    }
+        for ((x, y), tile) in trail_map.map.iter() {
+            match tile.tile_type {
+                TileType::Forest => continue,
+                _ => {
+                    let pos = (y, x);
+                    Self::create_node(&trail_map.map, pos, &mut nodes);
                 }
             }
         }
 
-        Self::compress_graph(&mut nodes, trail_map.map.len());
+        Self::compress_graph(&mut nodes, trail_map.map.height());
 
         println!("Nodes in graph: {}", nodes.len());
 
         let (start_pos, end_pos) = TrailMap::determine_start_and_end_tile_pos(&trail_map.map);
 
-        TrailGraph { nodes: nodes, start_pos: start_pos, end_pos: end_pos }
+        Ok(TrailGraph { nodes: nodes, start_pos: start_pos, end_pos: end_pos })
     }
 }
\ No newline at end of file
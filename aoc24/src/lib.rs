@@ -0,0 +1,34 @@
+pub mod task1;
+pub mod task2;
+pub mod utils;
+
+use aoc_helper::{Answer, Input, ParseError, Solver};
+
+use crate::utils::HailstoneMap;
+
+pub struct Day24 {
+    hailstone_map: HailstoneMap
+}
+
+impl Solver for Day24 {
+    fn parse_input(input: &Input) -> Result<Self, ParseError> {
+        return Ok(Day24 { hailstone_map: HailstoneMap::parse(input)? });
+    }
+
+    fn part1(&self) -> Answer {
+        return task1::solve(&self.hailstone_map).into();
+    }
+
+    fn part2(&self) -> Answer {
+        return task2::solve(&self.hailstone_map).into();
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE_INPUT: &str = "\
+19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3
+";
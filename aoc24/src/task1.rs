@@ -1,13 +1,18 @@
+use aoc_helper::Solver;
+
+use crate::Day24;
 use crate::utils::HailstoneMap;
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_example_input() {
-        let hailstone_map = HailstoneMap::parse("../input/24_1_example_input.txt");
+        let hailstone_map = HailstoneMap::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
         let start = (7, 7, 0);
         let end = (27, 27, 0);
         let xy_intersections = hailstone_map.find_xy_intersections_in_area(start, end);
@@ -16,11 +21,15 @@ mod tests {
     }
 }
 
-pub fn run_task() {
-    let hailstone_map = HailstoneMap::parse("input/24_1_input.txt");
+pub fn solve(hailstone_map: &HailstoneMap) -> u64 {
     let start = (200000000000000, 200000000000000, 0);
     let end = (400000000000000, 400000000000000, 0);
-    let xy_intersections = hailstone_map.find_xy_intersections_in_area(start, end);
 
-    println!("XY intersections: {}", xy_intersections);
-}
\ No newline at end of file
+    return hailstone_map.find_xy_intersections_in_area(start, end);
+}
+
+pub fn run_task(file: &str) {
+    let day = Day24::parse_file(file).unwrap();
+
+    println!("XY intersections: {}", day.part1());
+}
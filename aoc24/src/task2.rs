@@ -1,13 +1,18 @@
+use aoc_helper::Solver;
+
+use crate::Day24;
 use crate::utils::HailstoneMap;
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_example_input() {
-        let hailstone_map = HailstoneMap::parse("../input/24_1_example_input.txt");
+        let hailstone_map = HailstoneMap::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
         let rock_start_pos = hailstone_map.find_rock_start_pos();
 
         assert_eq!(rock_start_pos, (24, 13, 10));
@@ -17,12 +22,14 @@ mod tests {
     }
 }
 
-pub fn run_task() {
-    let hailstone_map = HailstoneMap::parse("input/24_1_input.txt");
+pub fn solve(hailstone_map: &HailstoneMap) -> i64 {
     let rock_start_pos = hailstone_map.find_rock_start_pos();
 
-    let res = rock_start_pos.0 + rock_start_pos.1 + rock_start_pos.2;
+    return rock_start_pos.0 + rock_start_pos.1 + rock_start_pos.2;
+}
+
+pub fn run_task(file: &str) {
+    let day = Day24::parse_file(file).unwrap();
 
-    println!("Rock start pos: {:?}", rock_start_pos);
-    println!("Res is: {}", res);
-}
\ No newline at end of file
+    println!("Res is: {}", day.part2());
+}
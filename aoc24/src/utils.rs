@@ -1,9 +1,14 @@
 use regex::Regex;
 use float_cmp::approx_eq;
+use aoc_helper::{Input, Line, ParseError};
+use aoc_helper::parallel::Executor;
 
 pub type Pos = (i64, i64, i64);
 type FPos = (f64, f64, f64);
 
+// Sums of the absolute x and y velocities tried at once when brute forcing the rock velocity
+const BOUNDS_PER_BATCH: i64 = 8;
+
 enum Condition {
     GTE(i64),
     LTE(i64)
@@ -118,13 +123,15 @@ impl Hailstone {
         panic!("X adjusted velocity is 0");
     }
 
-    fn parse(line: &str) -> Hailstone {
-        let re = Regex::new(r"([-]*[0-9]+)").unwrap();
-        let values: Vec<i64> = re.captures_iter(line).map(|c| c.extract()).map(|(_, [val])| val.parse::<i64>().unwrap()).collect();
+    fn parse(line: &Line) -> Result<Hailstone, ParseError> {
+        let re = Regex::new(r"^\s*(-?[0-9]+),\s*(-?[0-9]+),\s*(-?[0-9]+)\s*@\s*(-?[0-9]+),\s*(-?[0-9]+),\s*(-?[0-9]+)\s*$").unwrap();
+        let captures = re.captures(line.text).ok_or_else(|| line.error(line.text, "a hailstone (<x>, <y>, <z> @ <vx>, <vy>, <vz>)"))?;
+        let (_, strs): (&str, [&str; 6]) = captures.extract();
+        let values = strs.iter().map(|val| line.parse::<i64>(val, "a number")).collect::<Result<Vec<i64>, ParseError>>()?;
         let start_pos = (values[0], values[1], values[2]);
         let velocity = (values[3], values[4], values[5]);
 
-        Hailstone { start_pos: start_pos, velocity: velocity }
+        Ok(Hailstone { start_pos: start_pos, velocity: velocity })
     }
 }
 
@@ -250,25 +257,30 @@ impl HailstoneMap {
 
     fn brute_force_velocities(hail: &Vec<Hailstone>) -> Pos{
         let neg_vec = vec![-1, 1];
+        let executor = Executor::default();
         let mut upper_bound = 0;
         loop {
-            for x in 0..(upper_bound + 1) {
-                // Note: Could probably add condition to avoid duplicate calculations
-
-                for x_neg in neg_vec.iter() {
-                    for y_neg in neg_vec.iter() {
-                        let rock_x_vel = x * x_neg;
-                        let rock_y_vel = (upper_bound - x) * y_neg;
-
-                        let res = Self::test_velocities(hail, rock_x_vel, rock_y_vel);
-                        if res.is_some() {
-                            return res.unwrap();
+            // Velocities of a few bounds at once, so there is enough to do for all threads
+            let mut velocities = Vec::new();
+            for _ in 0..BOUNDS_PER_BATCH {
+                for x in 0..(upper_bound + 1) {
+                    // Note: Could probably add condition to avoid duplicate calculations
+
+                    for x_neg in neg_vec.iter() {
+                        for y_neg in neg_vec.iter() {
+                            velocities.push((x * x_neg, (upper_bound - x) * y_neg));
                         }
                     }
                 }
+
+                upper_bound += 1;
             }
 
-            upper_bound += 1;
+            // Note: The first match in the order of the velocities, so the result doesn't depend on the threads
+            let res = executor.find_first(velocities, |(rock_x_vel, rock_y_vel)| Self::test_velocities(hail, rock_x_vel, rock_y_vel));
+            if let Some(res) = res {
+                return res;
+            }
         }
     }
 
@@ -290,10 +302,9 @@ impl HailstoneMap {
         return intersections_in_area.len() as u64;
     }
 
-    pub fn parse(file: &str) -> HailstoneMap {
-        let lines = aoc_helper::read_lines(file);
-        let hail = lines.into_iter().map(|line| Hailstone::parse(&line)).collect();
+    pub fn parse(input: &Input) -> Result<HailstoneMap, ParseError> {
+        let hail = input.lines().iter().map(|line| Hailstone::parse(line)).collect::<Result<_, _>>()?;
 
-        HailstoneMap { hail: hail }
+        Ok(HailstoneMap { hail: hail })
     }
 }
\ No newline at end of file
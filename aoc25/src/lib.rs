@@ -0,0 +1,20 @@
+pub mod task1;
+pub mod utils;
+
+use aoc_helper::{Answer, Input, ParseError, Solver};
+
+use crate::utils::WireMap;
+
+pub struct Day25 {
+    wire_map: WireMap
+}
+
+impl Solver for Day25 {
+    fn parse_input(input: &Input) -> Result<Self, ParseError> {
+        return Ok(Day25 { wire_map: WireMap::parse(input)? });
+    }
+
+    fn part1(&self) -> Answer {
+        return task1::solve(&self.wire_map).into();
+    }
+}
@@ -1,6 +1,5 @@
-mod task1;
-mod utils;
+use aoc25::task1;
 
 fn main() {
-    aoc_helper::benchmark(task1::run_task);
+    aoc_helper::benchmark(|| task1::run_task(&aoc_helper::input_path(25, 1)));
 }
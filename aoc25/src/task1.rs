@@ -1,21 +1,54 @@
+use aoc_helper::Solver;
+
+use crate::Day25;
 use crate::utils::WireMap;
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use aoc_helper::Input;
+
+    const EXAMPLE_INPUT: &str = "\
+jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr
+";
 
     #[test]
     fn test_example_input() {
-        let wire_map = WireMap::parse("../input/25_1_example_input.txt");
+        let wire_map = WireMap::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
         let result = wire_map.divide_into_two_groups();
         assert_eq!(result, 54);
     }
+
+    #[test]
+    fn test_to_dot() {
+        let wire_map = WireMap::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
+        let dot = wire_map.to_dot();
+
+        assert_eq!(dot.lines().filter(|line| line.contains(" -- ")).count(), 33);
+        assert!(dot.contains("    \"jqt\" -- \"rhn\";\n"));
+        assert!(dot.contains("    \"cmg\" -- \"rzs\";\n"));
+    }
+}
+
+pub fn solve(wire_map: &WireMap) -> usize {
+    return wire_map.divide_into_two_groups();
 }
 
-pub fn run_task() {
-    let wire_map = WireMap::parse("input/25_1_input.txt");
-    let result = wire_map.divide_into_two_groups();
+pub fn run_task(file: &str) {
+    let day = Day25::parse_file(file).unwrap();
 
-    println!("Result is: {}", result);
-}
\ No newline at end of file
+    println!("Result is: {}", day.part1());
+}
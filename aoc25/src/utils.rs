@@ -2,6 +2,8 @@ use std::collections::HashMap;
 
 use rand::Rng;
 use rand::rngs::ThreadRng;
+use aoc_helper::{Input, Line, ParseError};
+use aoc_helper::dot::Dot;
 
 #[derive(Debug, Clone)]
 struct Node {
@@ -60,9 +62,14 @@ impl Node {
         }
     }
 
-    fn parse(line: &str, nodes: &mut HashMap<String, Node>) -> usize {
-        let mut split_line = line.split(": ");
+    fn parse(line: &Line, nodes: &mut HashMap<String, Node>) -> Result<usize, ParseError> {
+        let mut split_line = line.text.split(": ");
         let name = split_line.next().unwrap();
+        let connections_str = split_line.next().ok_or_else(|| line.end("': ' followed by connected components"))?;
+
+        if name.is_empty() {
+            return Err(line.error(name, "a component name"));
+        }
 
         // Check if node already exists
         let mut node_wrapped = nodes.get_mut(name);
@@ -74,7 +81,11 @@ impl Node {
         node = node_wrapped.unwrap();
 
         // Add connected nodes
-        let connections: Vec<&str> = split_line.next().unwrap().split(" ").collect();
+        let connections: Vec<&str> = connections_str.split(" ").collect();
+        if let Some(connection) = connections.iter().find(|connection| connection.is_empty()) {
+            return Err(line.error(connection, "a component name"));
+        }
+
         for connection in connections.iter() {
             Self::append_or_create_connection(node, *connection);
         }
@@ -93,7 +104,7 @@ impl Node {
             Self::append_or_create_connection(connected_node, name);
         }
 
-        return connections.len();
+        return Ok(connections.len());
     }
 }
 
@@ -165,17 +176,40 @@ impl WireMap {
         return group1_len * group2_len;
     }
 
-    pub fn parse(file: &str) -> WireMap {
-        let lines = aoc_helper::read_lines(file);
+    /// The components and their wires in the DOT language of Graphviz, multiple wires between two components are
+    /// drawn as one labelled with their count
+    pub fn to_dot(&self) -> String {
+        let mut dot = Dot::undirected("wires");
+
+        let mut names: Vec<&String> = self.nodes.keys().collect();
+        names.sort();
+
+        for name in names {
+            let mut connections: Vec<(&String, &usize)> = self.nodes[name].connections.iter().filter(|(other, _)| *other > name).collect();
+            connections.sort();
+
+            for (other, count) in connections {
+                if *count == 1 {
+                    dot.edge(name, other, &[]);
+                } else {
+                    dot.edge(name, other, &[("label", &count.to_string())]);
+                }
+            }
+        }
+
+        return dot.finish();
+    }
+
+    pub fn parse(input: &Input) -> Result<WireMap, ParseError> {
         let mut nodes = HashMap::new();
 
         let mut sum = 0;
-        for line in lines.into_iter() {
-            sum += Node::parse(&line, &mut nodes);
+        for line in input.lines().iter() {
+            sum += Node::parse(line, &mut nodes)?;
         }
 
         println!("Connections: {}", sum);
 
-        WireMap { nodes: nodes }
+        Ok(WireMap { nodes: nodes })
     }
 }
\ No newline at end of file
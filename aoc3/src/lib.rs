@@ -0,0 +1,39 @@
+pub mod task1;
+pub mod task2;
+pub mod utils;
+
+use aoc_helper::{Answer, Input, ParseError, Solver};
+
+use crate::utils::EnginePart;
+
+pub struct Day3 {
+    engine_parts: Vec<EnginePart>
+}
+
+impl Solver for Day3 {
+    fn parse_input(input: &Input) -> Result<Self, ParseError> {
+        return Ok(Day3 { engine_parts: EnginePart::parse_all(input)? });
+    }
+
+    fn part1(&self) -> Answer {
+        return task1::solve(&self.engine_parts).into();
+    }
+
+    fn part2(&self) -> Answer {
+        return task2::solve(&self.engine_parts).into();
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE_INPUT: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
@@ -1,26 +1,41 @@
+use aoc_helper::Solver;
+
+use crate::Day3;
 use crate::utils::EnginePart;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_example_input() {
-        let schematic_file = "../input/3_1_example_input.txt";
-        let engine_parts = EnginePart::parse_all(schematic_file);
+        let schematic = Input::new(EXAMPLE_INPUT);
+        let engine_parts = EnginePart::parse_all(&schematic).unwrap();
         
         // Note: Could assert each individual engine part number
 
         let sum: u32 = engine_parts.iter().map(|ep| ep.number).sum();
         assert_eq!(sum, 4361);
     }
+
+    #[test]
+    fn test_ragged_lines() {
+        // Two bytes, but a single char
+        let error = EnginePart::parse_all(&Input::new("abc\néa\n")).err().unwrap();
+        assert_eq!(error.to_string(), "row 2: expected a row of width 3, found width 2");
+
+        assert!(EnginePart::parse_all(&Input::new("1é*\n.é.\n")).is_ok());
+    }
 }
 
-pub fn run_task() {
-    let schematic_file = "input/3_1_input.txt";
-    let engine_parts = EnginePart::parse_all(schematic_file);
+pub fn solve(engine_parts: &Vec<EnginePart>) -> u32 {
+    return engine_parts.iter().map(|ep| ep.number).sum();
+}
 
-    let sum: u32 = engine_parts.iter().map(|ep| ep.number).sum();
+pub fn run_task(file: &str) {
+    let day = Day3::parse_file(file).unwrap();
 
-    println!("Sum is {}", sum);
-}
\ No newline at end of file
+    println!("Sum is {}", day.part1());
+}
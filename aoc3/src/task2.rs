@@ -1,14 +1,19 @@
+use aoc_helper::Solver;
+
+use crate::Day3;
 use crate::utils::EnginePart;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_example_input() {
-        let schematic_file = "../input/3_1_example_input.txt";
+        let schematic = Input::new(EXAMPLE_INPUT);
 
-        let engine_parts = EnginePart::parse_all(schematic_file);
+        let engine_parts = EnginePart::parse_all(&schematic).unwrap();
         engine_parts.iter().for_each(|ep| println!("{:?}", ep));
 
         let gears = EnginePart::filter_gears(&engine_parts);
@@ -24,18 +29,19 @@ mod tests {
     }
 }
 
-pub fn run_task() {
-    let schematic_file = "input/3_1_input.txt";
-
-    let engine_parts = EnginePart::parse_all(schematic_file);
-
-    let gears = EnginePart::filter_gears(&engine_parts);
-
+pub fn solve(engine_parts: &Vec<EnginePart>) -> u32 {
+    let gears = EnginePart::filter_gears(engine_parts);
 
     let mut sum = 0;
     for (part_1, part_2) in gears {
         sum += part_1.gear_ratio(part_2);
     }
-    
-    println!("Sum is {}", sum);
-}
\ No newline at end of file
+
+    return sum;
+}
+
+pub fn run_task(file: &str) {
+    let day = Day3::parse_file(file).unwrap();
+
+    println!("Sum is {}", day.part2());
+}
@@ -1,5 +1,8 @@
 use std::collections::VecDeque;
 
+use aoc_helper::{Input, ParseError};
+use aoc_helper::grid::Grid;
+
 #[derive(Debug)]
 pub struct UnconfirmedPart {
     number_str: String,
@@ -85,19 +88,22 @@ impl EnginePart {
         }
     }
 
-    pub fn parse_all(schematic_file: &str) -> Vec<EnginePart> {
+    pub fn parse_all(input: &Input) -> Result<Vec<EnginePart>, ParseError> {
         let mut engine_parts = Vec::new();
-        let mut lines = aoc_helper::read_lines_deque(schematic_file);
-        let line_len = lines[0].len();
+        if input.lines().is_empty() {
+            return Err(input.end("an engine schematic"));
+        }
+
+        // Note: All lines must be equally long for the parallel parsing below, which the grid checks
+        let schematic = Grid::from_rows(input.lines().iter().map(|line| line.text.chars().collect()).collect())?;
 
-        // Add lines to front and back to allow for 3 line parallel parsing (only accept engine parts in middle line)
-        lines.push_front(".".repeat(line_len));
-        lines.push_back(".".repeat(line_len));
+        // Add an empty border to allow for 3 line parallel parsing (only accept engine parts in middle line)
+        let schematic = schematic.padded(1, '.');
 
-        for y in 0..(lines.len() - 2) {
-            let mut l1 = lines[y].chars();
-            let mut l2 = lines[y + 1].chars().zip(0isize..).peekable();
-            let mut l3 = lines[y + 2].chars();
+        for y in 0..(schematic.height() - 2) {
+            let mut l1 = schematic[y].iter().copied();
+            let mut l2 = schematic[y + 1].iter().copied().zip(0isize..).peekable();
+            let mut l3 = schematic[y + 2].iter().copied();
             let mut latest_special_c: Option<isize> = None;
             let mut unconf_part: Option<UnconfirmedPart> = None;
             let mut stars: Vec<Star> = Vec::new();
@@ -157,7 +163,7 @@ impl EnginePart {
             }
         }
 
-        return engine_parts;
+        return Ok(engine_parts);
     }
 
     pub fn filter_gears(engine_parts: &Vec<EnginePart>) -> Vec<(&EnginePart, &EnginePart)> {
@@ -0,0 +1,35 @@
+pub mod task1;
+pub mod task2;
+pub mod utils;
+
+use aoc_helper::{Answer, Input, ParseError, Solver};
+
+use crate::utils::ScratchCard;
+
+pub struct Day4 {
+    cards: Vec<ScratchCard>
+}
+
+impl Solver for Day4 {
+    fn parse_input(input: &Input) -> Result<Self, ParseError> {
+        return Ok(Day4 { cards: ScratchCard::parseAll(input)? });
+    }
+
+    fn part1(&self) -> Answer {
+        return task1::solve(&self.cards).into();
+    }
+
+    fn part2(&self) -> Answer {
+        return task2::solve(&self.cards).into();
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE_INPUT: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
@@ -1,12 +1,17 @@
+use aoc_helper::Solver;
+
+use crate::Day4;
 use crate::utils::ScratchCard;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_example_input() {
-        let cards = ScratchCard::parseAll("../input/4_1_example_input.txt");
+        let cards = ScratchCard::parseAll(&Input::new(EXAMPLE_INPUT)).unwrap();
         
         let score = cards.iter().map(|sc| sc.calculate_score()).sum::<u32>();
 
@@ -14,10 +19,12 @@ mod tests {
     }
 }
 
-pub fn run_task() {
-    let cards = ScratchCard::parseAll("input/4_1_input.txt");
-        
-    let score = cards.iter().map(|sc| sc.calculate_score()).sum::<u32>();
+pub fn solve(cards: &Vec<ScratchCard>) -> u32 {
+    return cards.iter().map(|sc| sc.calculate_score()).sum::<u32>();
+}
 
-    println!("Score is {}", score);
-}
\ No newline at end of file
+pub fn run_task(file: &str) {
+    let day = Day4::parse_file(file).unwrap();
+
+    println!("Score is {}", day.part1());
+}
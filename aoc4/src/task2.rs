@@ -1,14 +1,19 @@
 use std::collections::HashMap;
 
+use aoc_helper::Solver;
+
+use crate::Day4;
 use crate::utils::ScratchCard;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_example_input() {
-        let cards = ScratchCard::parseAll("../input/4_2_example_input.txt");
+        let cards = ScratchCard::parseAll(&Input::new(EXAMPLE_INPUT)).unwrap();
         
         let wins: Vec<u32> = cards.iter().map(|sc| sc.calculate_wins()).collect();
 
@@ -49,16 +54,16 @@ fn calculate_card_copies_hm(wins: &Vec<u32>) -> HashMap<usize, u32>{
     return res;
 }
 
-pub fn run_task() {
-    let cards = ScratchCard::parseAll("input/4_1_input.txt");
-        
+pub fn solve(cards: &Vec<ScratchCard>) -> u32 {
     let wins: Vec<u32> = cards.iter().map(|sc| sc.calculate_wins()).collect();
 
-
     let card_copies_hm = calculate_card_copies_hm(&wins);
 
+    return card_copies_hm.values().sum::<u32>();
+}
 
-    let copies = card_copies_hm.values().sum::<u32>();
+pub fn run_task(file: &str) {
+    let day = Day4::parse_file(file).unwrap();
 
-    println!("Card copies: {}", copies);
-}
\ No newline at end of file
+    println!("Card copies: {}", day.part2());
+}
@@ -1,3 +1,5 @@
+use aoc_helper::{Input, Line, ParseError};
+
 pub struct ScratchCard {
     winning_nbrs: Vec<u32>,
     nbrs: Vec<u32>,
@@ -39,29 +41,27 @@ impl ScratchCard {
         union.len().try_into().unwrap()
     }
 
-    fn parse_nbrs(nbrs_str: &str) -> Vec<u32> {
-        let tmp_str = nbrs_str.trim().replace("  ", " ");
-        tmp_str.split(" ").map(|ns| ns.parse::<u32>().unwrap()).collect()
+    fn parse_nbrs(line: &Line, nbrs_str: &str) -> Result<Vec<u32>, ParseError> {
+        nbrs_str.split_whitespace().map(|ns| line.parse::<u32>(ns, "a number")).collect()
     }
 
-    fn parse(line: &str) -> ScratchCard {
-        let all_nbrs_str = line.split(": ").last().unwrap();
+    fn parse(line: &Line) -> Result<ScratchCard, ParseError> {
+        let all_nbrs_str = line.text.split(": ").last().unwrap();
         let mut split_nbrs_str = all_nbrs_str.split(" | ");
 
-        let winning_nbrs = Self::parse_nbrs(split_nbrs_str.next().unwrap());
-        let nbrs = Self::parse_nbrs(split_nbrs_str.next().unwrap());
+        let winning_nbrs = Self::parse_nbrs(line, split_nbrs_str.next().unwrap())?;
+        let nbrs = Self::parse_nbrs(line, split_nbrs_str.next().ok_or_else(|| line.end("' | ' followed by numbers"))?)?;
 
-        ScratchCard { winning_nbrs: winning_nbrs, nbrs: nbrs }
+        Ok(ScratchCard { winning_nbrs: winning_nbrs, nbrs: nbrs })
     }
 
-    pub fn parseAll(file: &str) -> Vec<ScratchCard> {
-        let lines = aoc_helper::read_lines(file);
+    pub fn parseAll(input: &Input) -> Result<Vec<ScratchCard>, ParseError> {
         let mut res = Vec::new();
 
-        for line in lines.iter() {
-            res.push(Self::parse(line));
+        for line in input.lines().iter() {
+            res.push(Self::parse(line)?);
         }
 
-        return res;
+        return Ok(res);
     }
 }
\ No newline at end of file
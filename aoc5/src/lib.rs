@@ -0,0 +1,62 @@
+pub mod task1;
+pub mod task2;
+pub mod utils;
+
+use aoc_helper::{Answer, Input, ParseError, Solver};
+
+use crate::utils::Almanac;
+
+pub struct Day5 {
+    almanac: Almanac
+}
+
+impl Solver for Day5 {
+    fn parse_input(input: &Input) -> Result<Self, ParseError> {
+        return Ok(Day5 { almanac: Almanac::parse(input)? });
+    }
+
+    fn part1(&self) -> Answer {
+        return task1::solve(&self.almanac).into();
+    }
+
+    fn part2(&self) -> Answer {
+        return task2::solve(&self.almanac).into();
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE_INPUT: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
@@ -1,12 +1,17 @@
+use aoc_helper::Solver;
+
+use crate::Day5;
 use crate::utils::Almanac;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_example_input() {
-        let almanac = Almanac::parse("../input/5_1_example_input.txt");
+        let almanac = Almanac::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
 
         let seed_to_locations = almanac.convert_seeds();
 
@@ -16,10 +21,14 @@ mod tests {
     }
 }
 
-pub fn run_task() {
-    let almanac = Almanac::parse("input/5_1_input.txt");
+pub fn solve(almanac: &Almanac) -> u64 {
     let seed_to_locations = almanac.convert_seeds();
-    let min_seed_loc = *seed_to_locations.iter().map(|stl| stl.last().unwrap()).min().unwrap();
 
-    println!("Min seed location: {}", min_seed_loc);
-}
\ No newline at end of file
+    return *seed_to_locations.iter().map(|stl| stl.last().unwrap()).min().unwrap();
+}
+
+pub fn run_task(file: &str) {
+    let day = Day5::parse_file(file).unwrap();
+
+    println!("Min seed location: {}", day.part1());
+}
@@ -1,12 +1,19 @@
+use aoc_helper::Solver;
+
+use crate::Day5;
 use crate::utils::Almanac;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_helper::Input;
+    use aoc_helper::testing::Lcg;
+    use crate::utils::XToYMap;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_example_input() {
-        let almanac = Almanac::parse("../input/5_1_example_input.txt");
+        let almanac = Almanac::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
 
         let mut res = almanac.part_2();
 
@@ -15,14 +22,116 @@ mod tests {
         res = almanac.part_2_new();
 
         assert_eq!(res, 46);
+
+        res = almanac.part_2_intervals();
+
+        assert_eq!(res, 46);
+
+        assert_eq!(almanac.part_2_composed(), 46);
+        assert_eq!(almanac.part_2_new_composed(), 46);
+    }
+
+    #[test]
+    fn test_composed_map() {
+        let almanac = Almanac::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
+        let composed = almanac.composed_map();
+
+        let locations: Vec<u64> = almanac.seeds.iter().map(|seed| composed.lookup(*seed)).collect();
+        assert_eq!(locations, vec![82, 43, 86, 35]);
+        assert_eq!(composed.lookup_inverse(46), 82);
+        assert_eq!(composed.lookup(u64::MAX - 1), u64::MAX - 1);
+
+        // Overlapping ranges, the first one wins
+        let input = Input::new("a-to-b map:\n10 0 5\n20 3 5\n");
+        let map = XToYMap::parse(&input, &input.lines()).unwrap();
+        let normalised = map.normalised();
+        for value in 0..12 {
+            assert_eq!(normalised.lookup(value), map.map(value, false));
+        }
+    }
+
+    // Almanac where each map shuffles blocks of 0..100, so it can be brute forced both ways
+    fn random_almanac(rng: &mut Lcg) -> String {
+        let mut random = |n: u64| rng.below(n);
+
+        let seeds: Vec<String> = (0..4).map(|_| format!("{} {}", random(110), 1 + random(15))).collect();
+        let mut almanac = format!("seeds: {}\n", seeds.join(" "));
+
+        for i in 0..7 {
+            let mut blocks = Vec::new();
+            let mut start = 0;
+            while start < 100 {
+                let len = (1 + random(20)).min(100 - start);
+                blocks.push((start, len));
+                start += len;
+            }
+
+            // Blocks are placed next to each other in a random order, and listed in another random order
+            for j in (1..blocks.len()).rev() {
+                blocks.swap(j, random(j as u64 + 1) as usize);
+            }
+            let mut dest_start = 0;
+            let mut lines = Vec::new();
+            for (src_start, len) in blocks {
+                lines.push((dest_start, src_start, len));
+                dest_start += len;
+            }
+            for j in (1..lines.len()).rev() {
+                lines.swap(j, random(j as u64 + 1) as usize);
+            }
+
+            let category = |i: usize| match i { 0 => String::from("seed"), 7 => String::from("location"), _ => format!("c{}", i) };
+            almanac += &format!("\n{}-to-{} map:\n", category(i), category(i + 1));
+            for (dest, src, len) in lines {
+                almanac += &format!("{} {} {}\n", dest, src, len);
+            }
+        }
+
+        return almanac;
+    }
+
+    #[test]
+    fn test_intervals_against_brute_force() {
+        let mut rng = Lcg::new(5);
+
+        for _ in 0..50 {
+            let almanac = Almanac::parse(&Input::new(&random_almanac(&mut rng))).unwrap();
+
+            assert_eq!(almanac.validate(), Vec::new());
+
+            let res = almanac.part_2_intervals();
+
+            assert_eq!(res, almanac.part_2());
+            assert_eq!(res, almanac.part_2_new());
+            assert_eq!(res, almanac.part_2_composed());
+            assert_eq!(res, almanac.part_2_new_composed());
+        }
+    }
+
+    #[test]
+    fn test_composed_against_chained_maps() {
+        let mut rng = Lcg::new(7);
+
+        for _ in 0..50 {
+            let almanac = Almanac::parse(&Input::new(&random_almanac(&mut rng))).unwrap();
+            let composed = almanac.composed_map();
+
+            for value in 0..120 {
+                assert_eq!(composed.lookup(value), almanac.convert_seed_p2_optimized(value));
+                assert_eq!(composed.lookup_inverse(value), almanac.location_to_seed(value));
+                assert_eq!(almanac.maps[0].normalised().lookup(value), almanac.maps[0].map(value, false));
+            }
+        }
     }
 }
 
-pub fn run_task() {
-    let almanac = Almanac::parse("input/5_1_input.txt");
+pub fn solve(almanac: &Almanac) -> u64 {
+    // Note: `part_2` and `part_2_new` brute force the same answer, they are only used to check it in the tests
+    return almanac.part_2_intervals();
+}
 
-    // let res = almanac.part_2();
-    let res = almanac.part_2_new();
+pub fn run_task(file: &str) {
+    let day = Day5::parse_file(file).unwrap();
 
-    println!("Res is {}: ", res);
-}
\ No newline at end of file
+    println!("Res is {}: ", day.part2());
+}
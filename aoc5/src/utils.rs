@@ -1,5 +1,10 @@
 
-use chrono;
+use std::fmt;
+
+use aoc_helper::{Input, Line, ParseError};
+
+/// Values from `start` up to (not including) `start + len`, as `(start, len)`
+pub type Interval = (u64, u64);
 
 #[derive(Debug)]
 struct RangeMap {
@@ -8,9 +13,14 @@ struct RangeMap {
     range_len: u64,
 }
 
+/// Converts values of one category to another, e.g. `seed-to-soil`
 #[derive(Debug)]
 pub struct XToYMap {
-    range_maps: Vec<RangeMap>
+    pub source: String,
+    pub destination: String,
+    range_maps: Vec<RangeMap>,
+    // Indices of `range_maps` sorted by destination, only set for normalised maps
+    dest_order: Vec<usize>
 }
 
 
@@ -20,6 +30,39 @@ pub struct Almanac {
     pub maps: Vec<XToYMap>,
 }
 
+/// Problem found by [`Almanac::validate`], ranges are given by their position in the map (starting at 0)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlmanacIssue {
+    /// A range without any values
+    ZeroLength { map: String, range: usize },
+    /// Two ranges contain the same source values, only the first one is used for them
+    OverlappingSources { map: String, first: usize, second: usize },
+    /// Two ranges map values to the same destination values
+    OverlappingDestinations { map: String, first: usize, second: usize },
+    /// `value` is the destination of a range, but it is in no source range either so it also maps to itself
+    SharedDestination { map: String, value: u64 },
+    /// A map doesn't convert from the category the map before it converts to
+    BrokenChain { previous: String, next: String },
+    /// The first map doesn't convert from `seed`
+    WrongStart { map: String },
+    /// The last map doesn't convert to `location`
+    WrongEnd { map: String }
+}
+
+impl fmt::Display for AlmanacIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlmanacIssue::ZeroLength { map, range } => write!(f, "{}: range {} is empty", map, range),
+            AlmanacIssue::OverlappingSources { map, first, second } => write!(f, "{}: sources of ranges {} and {} overlap", map, first, second),
+            AlmanacIssue::OverlappingDestinations { map, first, second } => write!(f, "{}: destinations of ranges {} and {} overlap", map, first, second),
+            AlmanacIssue::SharedDestination { map, value } => write!(f, "{}: {} is mapped to by a range and by itself", map, value),
+            AlmanacIssue::BrokenChain { previous, next } => write!(f, "{} is followed by {}", previous, next),
+            AlmanacIssue::WrongStart { map } => write!(f, "{}: the first map should convert from seed", map),
+            AlmanacIssue::WrongEnd { map } => write!(f, "{}: the last map should convert to location", map),
+        }
+    }
+}
+
 pub struct Part2Seeds {
     pub seed_ranges: Vec<(u64, u64)>
 }
@@ -27,7 +70,7 @@ pub struct Part2Seeds {
 impl Part2Seeds {
     fn is_possible(&self, seed: u64) -> bool {
         for (seed_start, seed_len) in self.seed_ranges.iter() {
-            if seed >= *seed_start && seed < *seed_start + seed_len {
+            if seed >= *seed_start && seed < seed_start.saturating_add(*seed_len) {
                 return true;
             }
         }
@@ -52,11 +95,12 @@ impl Part2Seeds {
 
 impl RangeMap {
     pub fn map(&self, val: u64, rev: bool) -> Option<u64> {
-        if !rev && val >= self.src_range_start && val <= self.src_range_start + self.range_len - 1 {
+        // Note: Compared as offsets, so empty ranges don't underflow
+        if !rev && val >= self.src_range_start && val - self.src_range_start < self.range_len {
             let diff = (val - self.src_range_start) as i64; // Should never be negative
 
             return Some(self.dest_range_start + diff as u64)
-        } else if rev && val >= self.dest_range_start && val <= self.dest_range_start + self.range_len - 1 {
+        } else if rev && val >= self.dest_range_start && val - self.dest_range_start < self.range_len {
             let diff = (val - self.dest_range_start) as i64; // Should never be negative
 
             return Some(self.src_range_start + diff as u64)
@@ -65,14 +109,46 @@ impl RangeMap {
         None
     } 
 
-    pub fn parse(line: &str) -> RangeMap {
-        let values: Vec<u64> = line.split(" ").map(|s| s.parse::<u64>().unwrap()).collect();
+    // Split an interval into the part covered by this range (mapped already) and the parts before and after it
+    fn map_interval(&self, (start, len): Interval) -> (Option<Interval>, Vec<Interval>) {
+        // Note: Seed ranges can go past `u64::MAX`, there are no values there anyway
+        let end = start.saturating_add(len);
+        let overlap_start = start.max(self.src_range_start);
+        let overlap_end = end.min(self.src_range_start + self.range_len);
+
+        if overlap_start >= overlap_end {
+            return (None, vec![(start, len)]);
+        }
+
+        let mut rest = Vec::new();
+        if start < overlap_start {
+            rest.push((start, overlap_start - start));
+        }
+        if overlap_end < end {
+            rest.push((overlap_end, end - overlap_end));
+        }
+
+        let mapped = (self.dest_range_start + (overlap_start - self.src_range_start), overlap_end - overlap_start);
+
+        return (Some(mapped), rest);
+    }
+
+    pub fn parse(line: &Line) -> Result<RangeMap, ParseError> {
+        let tokens: Vec<&str> = line.text.split(" ").collect();
+        let values = tokens.iter().map(|s| line.parse::<u64>(s, "a number")).collect::<Result<Vec<u64>, ParseError>>()?;
 
-        if values.len() != 3 {
-            panic!("RangeMap values length is too short!")
+        if values.len() < 3 {
+            return Err(line.end("<destination start> <source start> <length>"));
+        } else if values.len() > 3 {
+            return Err(line.error(tokens[3], "end of line after 3 numbers"));
         }
 
-        RangeMap { dest_range_start: values[0], src_range_start: values[1], range_len: values[2] }
+        // Note: Checked here, so the interval maths can add lengths to the starts of ranges without overflowing
+        if values[0].checked_add(values[2]).is_none() || values[1].checked_add(values[2]).is_none() {
+            return Err(line.error(tokens[2], "a length that keeps the range below 18446744073709551615"));
+        }
+
+        Ok(RangeMap { dest_range_start: values[0], src_range_start: values[1], range_len: values[2] })
     }
 }
 
@@ -87,17 +163,193 @@ impl XToYMap {
         return seed;
     }
 
-    pub fn parse(lines: &[String]) -> XToYMap {
+    /// Map whole intervals at once, splitting them where different ranges apply
+    ///
+    /// Like [`XToYMap::map`], the first range containing a value maps it
+    pub fn map_intervals(&self, intervals: &Vec<Interval>) -> Vec<Interval> {
+        let mut mapped = Vec::new();
+        let mut unmapped: Vec<Interval> = intervals.iter().filter(|(_, len)| *len > 0).copied().collect();
+
+        for range_map in self.range_maps.iter() {
+            let mut rest = Vec::new();
+            for interval in unmapped {
+                let (mapped_part, rest_parts) = range_map.map_interval(interval);
+                mapped.extend(mapped_part);
+                rest.extend(rest_parts);
+            }
+            unmapped = rest;
+        }
+
+        // Values outside all ranges map to themselves
+        mapped.extend(unmapped);
+
+        return mapped;
+    }
+
+    /// The same map as ranges sorted by source that don't overlap, with identity ranges filling the gaps
+    ///
+    /// Every value below `u64::MAX` is in exactly one range, so [`XToYMap::lookup`] can binary search them
+    pub fn normalised(&self) -> XToYMap {
+        let mut range_maps = Vec::new();
+        let mut unmapped = vec![(0, u64::MAX)];
+
+        // Note: Like `map_intervals`, but remembering where each mapped part came from
+        for range_map in self.range_maps.iter() {
+            let mut rest = Vec::new();
+            for interval in unmapped {
+                let (mapped_part, rest_parts) = range_map.map_interval(interval);
+                if let Some((dest_start, len)) = mapped_part {
+                    let src_start = interval.0.max(range_map.src_range_start);
+                    range_maps.push(RangeMap { dest_range_start: dest_start, src_range_start: src_start, range_len: len });
+                }
+                rest.extend(rest_parts);
+            }
+            unmapped = rest;
+        }
+
+        for (start, len) in unmapped {
+            range_maps.push(RangeMap { dest_range_start: start, src_range_start: start, range_len: len });
+        }
+
+        return XToYMap::from_sorted_ranges(&self.source, &self.destination, range_maps);
+    }
+
+    /// A single normalised map that maps values with this map and then with `next`
+    pub fn compose(&self, next: &XToYMap) -> XToYMap {
+        let first = self.normalised();
+        let next = next.normalised();
+        let mut range_maps = Vec::new();
+
+        for range_map in first.range_maps.iter() {
+            // Note: Can't overflow, `RangeMap::parse` keeps all ranges below `u64::MAX` and normalising only splits them
+            let dest_start = range_map.dest_range_start;
+            let dest_end = dest_start + range_map.range_len;
+
+            // Split the destination of the range where it falls into different ranges of `next`
+            let mut i = next.range_maps.partition_point(|next_range| next_range.src_range_start + next_range.range_len <= dest_start);
+            while i < next.range_maps.len() && next.range_maps[i].src_range_start < dest_end {
+                let next_range = &next.range_maps[i];
+                let start = dest_start.max(next_range.src_range_start);
+                let end = dest_end.min(next_range.src_range_start + next_range.range_len);
+
+                range_maps.push(RangeMap {
+                    dest_range_start: next_range.dest_range_start + (start - next_range.src_range_start),
+                    src_range_start: range_map.src_range_start + (start - dest_start),
+                    range_len: end - start
+                });
+                i += 1;
+            }
+        }
+
+        return XToYMap::from_sorted_ranges(&self.source, &next.destination, range_maps);
+    }
+
+    // Normalised map of ranges that don't overlap, merging neighbours that shift values by the same amount
+    fn from_sorted_ranges(source: &str, destination: &str, mut range_maps: Vec<RangeMap>) -> XToYMap {
+        range_maps.sort_by_key(|range_map| range_map.src_range_start);
+
+        let mut merged: Vec<RangeMap> = Vec::new();
+        for range_map in range_maps {
+            if let Some(last) = merged.last_mut() {
+                if last.src_range_start + last.range_len == range_map.src_range_start && last.dest_range_start + last.range_len == range_map.dest_range_start {
+                    last.range_len += range_map.range_len;
+                    continue;
+                }
+            }
+            merged.push(range_map);
+        }
+
+        let mut dest_order: Vec<usize> = (0..merged.len()).collect();
+        dest_order.sort_by_key(|i| merged[*i].dest_range_start);
+
+        return XToYMap { source: source.to_owned(), destination: destination.to_owned(), range_maps: merged, dest_order: dest_order };
+    }
+
+    /// [`XToYMap::map`] using a binary search, for maps made by [`XToYMap::normalised`] or [`XToYMap::compose`]
+    pub fn lookup(&self, value: u64) -> u64 {
+        assert_eq!(self.dest_order.len(), self.range_maps.len(), "Only normalised maps can be looked up");
+
+        let i = self.range_maps.partition_point(|range_map| range_map.src_range_start <= value);
+        if i > 0 {
+            if let Some(res) = self.range_maps[i - 1].map(value, false) {
+                return res;
+            }
+        }
+
+        return value;
+    }
+
+    /// Reverse of [`XToYMap::lookup`], like [`XToYMap::map`] with `rev`
+    ///
+    /// Note: Only reliable if no two values map to the same value, otherwise the destinations of the ranges overlap
+    /// and the binary search can miss the right one
+    pub fn lookup_inverse(&self, value: u64) -> u64 {
+        assert_eq!(self.dest_order.len(), self.range_maps.len(), "Only normalised maps can be looked up");
+
+        let i = self.dest_order.partition_point(|j| self.range_maps[*j].dest_range_start <= value);
+        if i > 0 {
+            if let Some(res) = self.range_maps[self.dest_order[i - 1]].map(value, true) {
+                return res;
+            }
+        }
+
+        return value;
+    }
+
+    /// Name of the map as in its title, e.g. `seed-to-soil`
+    pub fn name(&self) -> String {
+        return format!("{}-to-{}", self.source, self.destination);
+    }
+
+    // Problems of the ranges of this map, see `Almanac::validate`
+    fn issues(&self) -> Vec<AlmanacIssue> {
+        let mut issues = Vec::new();
+
+        for (i, range_map) in self.range_maps.iter().enumerate() {
+            if range_map.range_len == 0 {
+                issues.push(AlmanacIssue::ZeroLength { map: self.name(), range: i });
+            }
+        }
+
+        let overlap = |start_a: u64, start_b: u64, len: u64, other_len: u64| start_a < start_b.saturating_add(other_len) && start_b < start_a.saturating_add(len);
+        for (i, first) in self.range_maps.iter().enumerate() {
+            for (j, second) in self.range_maps.iter().enumerate().skip(i + 1) {
+                if overlap(first.src_range_start, second.src_range_start, first.range_len, second.range_len) {
+                    issues.push(AlmanacIssue::OverlappingSources { map: self.name(), first: i, second: j });
+                }
+                if overlap(first.dest_range_start, second.dest_range_start, first.range_len, second.range_len) {
+                    issues.push(AlmanacIssue::OverlappingDestinations { map: self.name(), first: i, second: j });
+                }
+            }
+        }
+
+        // Destinations outside all sources are also reached by the values that map to themselves
+        let mut outside: Vec<Interval> = self.range_maps.iter().map(|range_map| (range_map.dest_range_start, range_map.range_len)).filter(|(_, len)| *len > 0).collect();
+        for range_map in self.range_maps.iter() {
+            outside = outside.into_iter().flat_map(|interval| range_map.map_interval(interval).1).collect();
+        }
+        if let Some(value) = outside.iter().map(|(start, _)| *start).min() {
+            issues.push(AlmanacIssue::SharedDestination { map: self.name(), value: value });
+        }
+
+        return issues;
+    }
+
+    /// Parse the lines of a single map of `input`, starting with its title
+    pub fn parse(input: &Input, lines: &[Line]) -> Result<XToYMap, ParseError> {
         let mut range_maps = Vec::new();
 
         let mut it = lines.iter();
-        it.next(); // Skip title string
+        let title = it.next().ok_or_else(|| input.end("<source>-to-<destination> map:"))?;
+        let (source, destination) = title.text.strip_suffix(" map:")
+            .and_then(|name| name.split_once("-to-"))
+            .ok_or_else(|| title.error(title.text, "<source>-to-<destination> map:"))?;
 
-        while let Some(line) = it.next() {
-            range_maps.push(RangeMap::parse(line));
+        for line in it {
+            range_maps.push(RangeMap::parse(line)?);
         }
 
-        return XToYMap { range_maps: range_maps }
+        return Ok(XToYMap { source: source.to_owned(), destination: destination.to_owned(), range_maps: range_maps, dest_order: Vec::new() })
     }
 }
 
@@ -125,7 +377,21 @@ impl Almanac {
         return res;
     }
 
-    fn location_to_seed(&self, loc: u64) -> u64 {
+    /// Location of a seed, going through every map in turn
+    ///
+    /// Note: Slow, but it doesn't depend on `composed_map`, so the tests use it to check the composed map
+    pub fn convert_seed_p2_optimized(&self, seed: u64) -> u64 {
+        let mut current_val = seed;
+
+        for map in self.maps.iter() {
+            current_val = map.map(current_val, false);
+        }
+
+        return current_val;
+    }
+
+    /// Seed of a location, going back through every map in turn (see [`Almanac::convert_seed_p2_optimized`])
+    pub fn location_to_seed(&self, loc: u64) -> u64 {
         let mut current_val = loc;
 
         for map in self.maps.iter().rev() {
@@ -134,7 +400,52 @@ impl Almanac {
         }
 
         return current_val;
-    }  
+    }
+
+    /// All maps composed into one, mapping seeds straight to locations
+    pub fn composed_map(&self) -> XToYMap {
+        let first = self.maps.first().expect("An almanac has at least one map").normalised();
+
+        return self.maps[1..].iter().fold(first, |composed, map| composed.compose(map));
+    }
+
+    /// Everything that makes the maps ambiguous or keeps them from chaining, empty if the almanac is fine
+    ///
+    /// Note: The puzzle inputs are fine. Parts 1 and 2 still work otherwise, but reverse lookups like
+    /// `location_to_seed` need every map to be a bijection
+    pub fn validate(&self) -> Vec<AlmanacIssue> {
+        let mut issues = Vec::new();
+
+        for map in self.maps.iter() {
+            issues.extend(map.issues());
+        }
+
+        for maps in self.maps.windows(2) {
+            if maps[0].destination != maps[1].source {
+                issues.push(AlmanacIssue::BrokenChain { previous: maps[0].name(), next: maps[1].name() });
+            }
+        }
+
+        // Parts 1 and 2 take the numbers of the first line as seeds and the last converted numbers as locations
+        if let Some(first) = self.maps.first().filter(|map| map.source != "seed") {
+            issues.push(AlmanacIssue::WrongStart { map: first.name() });
+        }
+        if let Some(last) = self.maps.last().filter(|map| map.destination != "location") {
+            issues.push(AlmanacIssue::WrongEnd { map: last.name() });
+        }
+
+        return issues;
+    }
+
+    /// Lowest location of all seeds in the seed ranges, by mapping the ranges through all maps as a whole
+    pub fn part_2_intervals(&self) -> u64 {
+        let mut intervals = Part2Seeds::new(&self.seeds).seed_ranges;
+        for map in self.maps.iter() {
+            intervals = map.map_intervals(&intervals);
+        }
+
+        return intervals.iter().map(|(start, _)| *start).min().unwrap();
+    }
 
     // 1.04s! (with optimizations i.e.: cargo run --release -p aoc5)
     pub fn part_2_new(&self) -> u64 {
@@ -145,7 +456,6 @@ impl Almanac {
             let unverified_seed = self.location_to_seed(loc);
 
             if part_2_seeds.is_possible(unverified_seed) {
-                println!("Seed: {}", unverified_seed);
                 return loc;
             }
 
@@ -153,37 +463,34 @@ impl Almanac {
         }
     }
 
-    // Skips generation of some vectors (Total time: 2116.55s -> 114.60s LOL!) (with optimizations i.e.: cargo run --release -p aoc5)
-    fn convert_seed_p2_optimized(&self, seed: u64) -> u64 {
-        let mut current_val = seed;
-
-        for map in self.maps.iter() {
-            current_val = map.map(current_val, false);
-        }
+    /// Like [`Almanac::part_2`], but looking the seeds up in the composed map
+    pub fn part_2_composed(&self) -> u64 {
+        // Note: Composed once, so every seed is a single lookup instead of going through all maps
+        let composed = self.composed_map();
 
-        return current_val;
+        return Part2Seeds::new(&self.seeds).seed_ranges.iter()
+            .flat_map(|(start, len)| *start..start.saturating_add(*len))
+            .map(|seed| composed.lookup(seed))
+            .min()
+            .unwrap_or(u64::MAX);
     }
 
-    fn part_2_combinations(&self) {
-        let mut tmp_it = self.seeds.iter().peekable();
-        let mut combinations:u64 = 0;
+    /// Like [`Almanac::part_2_new`], but looking the locations up in the composed map
+    pub fn part_2_new_composed(&self) -> u64 {
+        let part_2_seeds = Part2Seeds::new(&self.seeds);
+        let composed = self.composed_map();
 
-        while tmp_it.peek().is_some() {
-            tmp_it.next();
-            combinations += tmp_it.next().unwrap();
+        let mut loc = 0;
+        while !part_2_seeds.is_possible(composed.lookup_inverse(loc)) {
+            loc += 1;
         }
 
-         //2 387 882 574
-        println!("Seeds/combinations: {}", combinations);
+        return loc;
     }
 
     pub fn part_2(&self) -> u64 {
-        self.part_2_combinations();
-        println!("{:?}", chrono::offset::Local::now());
-
         let mut it = self.seeds.iter().peekable();
         let mut res = u64::MAX;
-        let mut progress:u32 = 0;
 
         while it.peek().is_some() {
             let seed_start = *it.next().unwrap();
@@ -191,11 +498,6 @@ impl Almanac {
 
             for i in 0..seed_range_len {
                 let seed = seed_start + i;
-                progress += 1;
-
-                if progress % 100000000 == 0 { // 100 000 000
-                    println!("{:?}: Progress: {}", chrono::offset::Local::now(), progress);
-                }
 
                 //let tmp_min = *self.convert_seed(seed).last().unwrap();
                 let tmp_min = self.convert_seed_p2_optimized(seed);
@@ -208,12 +510,14 @@ impl Almanac {
         return res;
     }
 
-    pub fn parse(file: &str) -> Almanac {
-        let lines = aoc_helper::read_lines(file);
+    pub fn parse(input: &Input) -> Result<Almanac, ParseError> {
+        let lines = input.lines();
         let mut it = lines.iter().enumerate().peekable();
 
         // Parse seeds: Nice :D
-        let seeds = it.next().unwrap().1.split(": ").last().unwrap().split(" ").map(|s| s.parse::<u64>().unwrap()).collect();
+        let seeds_line = it.next().ok_or_else(|| input.end("seeds: <numbers>"))?.1;
+        let seeds_str = seeds_line.text.strip_prefix("seeds: ").ok_or_else(|| seeds_line.error(seeds_line.text, "seeds: <numbers>"))?;
+        let seeds = seeds_str.split(" ").map(|s| seeds_line.parse::<u64>(s, "a seed number")).collect::<Result<_, _>>()?;
 
         it.next(); // Skip empty line
 
@@ -223,22 +527,88 @@ impl Almanac {
         let mut map_start_index = 0;
         let mut map_end_index;
         while let Some((i, line)) = it.next() {
-            if line.contains("map") {
+            if line.text.contains("map") {
                 map_start_index = i;
             }
             
             let last_line = it.peek().is_none();
-            if line.is_empty() || last_line { // Aka end of a XToYMap
+            if line.text.is_empty() || last_line { // Aka end of a XToYMap
                 map_end_index = i;
                 if last_line {
                     map_end_index += 1;
                 }
                 let map_lines = &lines[map_start_index..map_end_index];
-                let map = XToYMap::parse(map_lines);
+                let map = XToYMap::parse(input, map_lines)?;
                 maps.push(map);
             }
         }
 
-        return Almanac { seeds: seeds, maps: maps }
+        return Ok(Almanac { seeds: seeds, maps: maps })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EXAMPLE_INPUT;
+
+    #[test]
+    fn test_validate() {
+        let almanac = Almanac::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
+        assert_eq!(almanac.validate(), Vec::new());
+        assert_eq!(almanac.maps[0].name(), "seed-to-soil");
+
+        let input = Input::new("\
+seeds: 1 2
+
+seed-to-soil map:
+10 0 5
+20 3 5
+30 40 0
+
+water-to-light map:
+5 0 5
+7 10 5
+");
+        let almanac = Almanac::parse(&input).unwrap();
+        let map = String::from("seed-to-soil");
+
+        assert_eq!(almanac.validate(), vec![
+            AlmanacIssue::ZeroLength { map: map.clone(), range: 2 },
+            AlmanacIssue::OverlappingSources { map: map.clone(), first: 0, second: 1 },
+            AlmanacIssue::SharedDestination { map: map.clone(), value: 10 },
+            AlmanacIssue::OverlappingDestinations { map: String::from("water-to-light"), first: 0, second: 1 },
+            AlmanacIssue::SharedDestination { map: String::from("water-to-light"), value: 5 },
+            AlmanacIssue::BrokenChain { previous: map.clone(), next: String::from("water-to-light") },
+            AlmanacIssue::WrongEnd { map: String::from("water-to-light") },
+        ]);
+        assert_eq!(AlmanacIssue::WrongEnd { map: String::from("water-to-light") }.to_string(), "water-to-light: the last map should convert to location");
+
+        let wrong_start = Almanac::parse(&Input::new("seeds: 1 2\n\nsoil-to-location map:\n2 2 3\n")).unwrap();
+        assert_eq!(wrong_start.validate(), vec![AlmanacIssue::WrongStart { map: String::from("soil-to-location") }]);
+        assert_eq!(almanac.maps[0].map(40, false), 40);
+
+        let bad_title = Input::new("seeds: 1 2\n\nseed to soil map:\n1 2 3\n");
+        assert!(Almanac::parse(&bad_title).is_err());
+        assert_eq!(XToYMap::parse(&bad_title, &[]).err().unwrap().to_string(), "<input>:5: unexpected end of input, expected <source>-to-<destination> map:");
+    }
+    #[test]
+    fn test_ranges_near_max() {
+        let input = Input::new("\
+seeds: 18446744073709551605 100
+
+seed-to-soil map:
+0 18446744073709551600 10
+
+soil-to-location map:
+18446744073709551000 0 10
+");
+        let almanac = Almanac::parse(&input).unwrap();
+
+        assert_eq!(almanac.part_2_intervals(), 18446744073709551005);
+        assert_eq!(almanac.composed_map().lookup(18446744073709551607), 18446744073709551007);
+
+        let too_long = Input::new("seeds: 1 2\n\nseed-to-soil map:\n0 18446744073709551610 10\n");
+        assert_eq!(Almanac::parse(&too_long).err().unwrap().to_string(), "<input>:4:24: expected a length that keeps the range below 18446744073709551615, found \"10\"");
     }
-}
\ No newline at end of file
+}
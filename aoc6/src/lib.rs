@@ -0,0 +1,35 @@
+pub mod task1;
+pub mod task2;
+pub mod utils;
+
+use aoc_helper::{Answer, Input, ParseError, Solver};
+
+use crate::utils::Race;
+
+pub struct Day6 {
+    races: Vec<Race>,
+    race: Race
+}
+
+impl Solver for Day6 {
+    fn parse_input(input: &Input) -> Result<Self, ParseError> {
+        return Ok(Day6 {
+            races: Race::parse_all(input)?,
+            race: Race::parse_part_2(input)?
+        });
+    }
+
+    fn part1(&self) -> Answer {
+        return task1::solve(&self.races).into();
+    }
+
+    fn part2(&self) -> Answer {
+        return task2::solve(&self.race).into();
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE_INPUT: &str = "\
+Time:      7  15   30
+Distance:  9  40  200
+";
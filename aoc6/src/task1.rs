@@ -1,12 +1,17 @@
+use aoc_helper::Solver;
+
+use crate::Day6;
 use crate::utils::Race;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_example_input() {
-        let races = Race::parse_all("../input/6_1_example_input.txt");
+        let races = Race::parse_all(&Input::new(EXAMPLE_INPUT)).unwrap();
 
         let posibilities: Vec<u64> = races.iter().map(|race| race.beat_record_possibilities()).collect();
 
@@ -21,9 +26,7 @@ mod tests {
     }
 }
 
-pub fn run_task() {
-    let races = Race::parse_all("input/6_1_input.txt");
-
+pub fn solve(races: &Vec<Race>) -> u64 {
     let posibilities: Vec<u64> = races.iter().map(|race| race.beat_record_possibilities()).collect();
 
     let mut res = 1;
@@ -31,5 +34,11 @@ pub fn run_task() {
         res *= posibility;
     }
 
-    println!("Res is {}", res);
-}
\ No newline at end of file
+    return res;
+}
+
+pub fn run_task(file: &str) {
+    let day = Day6::parse_file(file).unwrap();
+
+    println!("Res is {}", day.part1());
+}
@@ -1,12 +1,17 @@
+use aoc_helper::Solver;
+
+use crate::Day6;
 use crate::utils::Race;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_example_input() {
-        let race = Race::parse_part_2("../input/6_1_example_input.txt");
+        let race = Race::parse_part_2(&Input::new(EXAMPLE_INPUT)).unwrap();
 
         let posibilities = race.beat_record_possibilities();
 
@@ -14,10 +19,12 @@ mod tests {
     }
 }
 
-pub fn run_task() {
-    let race = Race::parse_part_2("input/6_1_input.txt");
+pub fn solve(race: &Race) -> u64 {
+    return race.beat_record_possibilities();
+}
 
-    let posibilities = race.beat_record_possibilities();
+pub fn run_task(file: &str) {
+    let day = Day6::parse_file(file).unwrap();
 
-    println!("Posibilities is {}", posibilities);
-}
\ No newline at end of file
+    println!("Posibilities is {}", day.part2());
+}
@@ -1,3 +1,5 @@
+use aoc_helper::{Input, Line, ParseError};
+
 pub struct Race {
     time: u64,
     distance: u64,
@@ -37,43 +39,59 @@ impl Race {
         return end_beat_time - start_beat_time + 1
     }
 
-    fn parse_numbers(line: &str) -> Vec<u64> {
+    fn parse_numbers(line: &Line) -> Result<Vec<u64>, ParseError> {
         let mut res = Vec::new();
 
-        line.split(":").last().unwrap().split(" ").for_each(|s| {
+        for s in line.text.split(":").last().unwrap().split(" ") {
             if !s.trim().is_empty() {
-                res.push(s.parse::<u64>().unwrap());
+                res.push(line.parse::<u64>(s, "a number")?);
             }
-        });
+        }
 
-        return res;
+        return Ok(res);
     }
 
-    fn parse_kerning_number(line: &str) -> u64 {
-        let split_line = line.split(":").last().unwrap();
+    fn parse_kerning_number(line: &Line) -> Result<u64, ParseError> {
+        let split_line = line.text.split(":").last().unwrap();
         let trimmed_line = split_line.replace(" ", "");
 
         println!("Trimmed line is: {}", trimmed_line);
 
-        trimmed_line.parse::<u64>().unwrap()
+        trimmed_line.parse::<u64>().map_err(|_| line.error(split_line.trim(), "a number (spaces are ignored)"))
     }
 
-    pub fn parse_part_2(file: &str) -> Race {
-        let lines = aoc_helper::read_lines(file);
+    // Both parsers expect a time line followed by a distance line
+    fn check_input(input: &Input) -> Result<(), ParseError> {
+        match input.lines().len() {
+            0 => return Err(input.end("Time: <numbers>")),
+            1 => return Err(input.end("Distance: <numbers>")),
+            _ => return Ok(())
+        }
+    }
+
+    pub fn parse_part_2(input: &Input) -> Result<Race, ParseError> {
+        Self::check_input(input)?;
+        let lines = input.lines();
         let mut it = lines.iter();
 
-        let time = Self::parse_kerning_number(it.next().unwrap());
-        let distance = Self::parse_kerning_number(it.next().unwrap());
+        let time = Self::parse_kerning_number(it.next().unwrap())?;
+        let distance = Self::parse_kerning_number(it.next().unwrap())?;
 
-        return Race { time: time, distance: distance };
+        return Ok(Race { time: time, distance: distance });
     }
 
-    pub fn parse_all(file: &str) -> Vec<Race> {
-        let lines = aoc_helper::read_lines(file);
+    pub fn parse_all(input: &Input) -> Result<Vec<Race>, ParseError> {
+        Self::check_input(input)?;
+        let lines = input.lines();
         let mut it = lines.iter();
 
-        let times = Self::parse_numbers(it.next().unwrap());
-        let distances: Vec<u64> = Self::parse_numbers(it.next().unwrap());
+        let times = Self::parse_numbers(it.next().unwrap())?;
+        let distance_line = it.next().unwrap();
+        let distances: Vec<u64> = Self::parse_numbers(distance_line)?;
+
+        if distances.len() != times.len() {
+            return Err(distance_line.error(distance_line.text, &format!("{} distances", times.len())));
+        }
 
         println!("Times: {:?}, distances: {:?}", times, distances);
 
@@ -82,6 +100,6 @@ impl Race {
             races.push(Race { time: *time, distance: *distance })
         }
 
-        return races;
+        return Ok(races);
     }
 }
\ No newline at end of file
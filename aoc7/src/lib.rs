@@ -0,0 +1,38 @@
+pub mod task1;
+pub mod task2;
+pub mod utils;
+
+use aoc_helper::{Answer, Input, ParseError, Solver};
+
+use crate::utils::Hand;
+
+pub struct Day7 {
+    hands: Vec<Hand>,
+    hands_joker: Vec<Hand>
+}
+
+impl Solver for Day7 {
+    fn parse_input(input: &Input) -> Result<Self, ParseError> {
+        return Ok(Day7 {
+            hands: Hand::parse_all(input, false)?,
+            hands_joker: Hand::parse_all(input, true)?
+        });
+    }
+
+    fn part1(&self) -> Answer {
+        return task1::solve(&self.hands).into();
+    }
+
+    fn part2(&self) -> Answer {
+        return task2::solve(&self.hands_joker).into();
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE_INPUT: &str = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+";
@@ -1,12 +1,17 @@
+use aoc_helper::Solver;
+
+use crate::Day7;
 use crate::utils::Hand;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_example_input() {
-        let mut hands = Hand::parse_all("../input/7_1_example_input.txt", true);
+        let mut hands = Hand::parse_all(&Input::new(EXAMPLE_INPUT), true).unwrap();
         hands.sort();
 
         let mut total_winnings = 0;
@@ -19,8 +24,8 @@ mod tests {
     }
 }
 
-pub fn run_task() {
-    let mut hands = Hand::parse_all("input/7_1_input.txt", true);
+pub fn solve(hands: &Vec<Hand>) -> u64 {
+    let mut hands: Vec<&Hand> = hands.iter().collect();
     hands.sort();
 
     let mut total_winnings = 0;
@@ -29,5 +34,11 @@ pub fn run_task() {
         total_winnings += rank * hand.bid;
     }
 
-    println!("Total winnings (w. joker): {}", total_winnings);
-}
\ No newline at end of file
+    return total_winnings;
+}
+
+pub fn run_task(file: &str) {
+    let day = Day7::parse_file(file).unwrap();
+
+    println!("Total winnings (w. joker): {}", day.part2());
+}
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 use std::cmp::Ordering;
 
+use aoc_helper::{Input, Line, ParseError};
+
 #[derive(Clone)]
 #[derive(Debug)]
 #[derive(PartialEq)]
@@ -193,17 +195,22 @@ impl Hand {
         Hand { hand: hand, bid: bid, hand_type: hand_type, joker: joker }
     }
 
-    fn parse(line: &str, joker: bool) -> Hand {
-        let mut split_line = line.split(" ");
-        let hand_str = split_line.next().unwrap().to_owned();
-        let bid = split_line.next().unwrap().parse::<u64>().unwrap();
+    fn parse(line: &Line, joker: bool) -> Result<Hand, ParseError> {
+        let mut split_line = line.text.split(" ");
+        let hand_str = split_line.next().unwrap();
+
+        if hand_str.len() != 5 || !hand_str.chars().all(|c| "23456789TJQKA".contains(c)) {
+            return Err(line.error(hand_str, "a hand of 5 cards (23456789TJQKA)"));
+        }
+
+        let bid_str = split_line.next().ok_or_else(|| line.end("a bid"))?;
+        let bid = line.parse::<u64>(bid_str, "a bid")?;
 
-        Hand::new(hand_str, bid, joker)
+        Ok(Hand::new(hand_str.to_owned(), bid, joker))
     }
 
-    pub fn parse_all(file: &str, joker: bool) -> Vec<Hand> {
-        let lines = aoc_helper::read_lines(file);
+    pub fn parse_all(input: &Input, joker: bool) -> Result<Vec<Hand>, ParseError> {
 
-        lines.iter().map(|line| Hand::parse(line, joker)).collect()
+        input.lines().iter().map(|line| Hand::parse(line, joker)).collect()
     }
 }
\ No newline at end of file
@@ -0,0 +1,25 @@
+pub mod task1;
+pub mod task2;
+pub mod utils;
+
+use aoc_helper::{Answer, Input, ParseError, Solver};
+
+use crate::utils::Network;
+
+pub struct Day8 {
+    network: Network
+}
+
+impl Solver for Day8 {
+    fn parse_input(input: &Input) -> Result<Self, ParseError> {
+        return Ok(Day8 { network: Network::parse(input)? });
+    }
+
+    fn part1(&self) -> Answer {
+        return task1::solve(&self.network).into();
+    }
+
+    fn part2(&self) -> Answer {
+        return task2::solve(&self.network).into();
+    }
+}
@@ -1,13 +1,37 @@
+use aoc_helper::Solver;
+
+use crate::Day8;
 use crate::utils::Network;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_helper::Input;
+
+    const EXAMPLE_INPUT_1: &str = "\
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+";
+
+    const EXAMPLE_INPUT_2: &str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+";
 
     #[test]
     fn test_example_input() {
-        let network_1 = Network::parse("../input/8_1_example_input_1.txt");
-        let network_2 = Network::parse("../input/8_1_example_input_2.txt");
+        let network_1 = Network::parse(&Input::new(EXAMPLE_INPUT_1)).unwrap();
+        let network_2 = Network::parse(&Input::new(EXAMPLE_INPUT_2)).unwrap();
 
         //println!("Net 1: {:?}", network_1);
         //println!("Net 2: {:?}", network_2);
@@ -18,12 +42,35 @@ mod tests {
         assert_eq!(steps_to_z_1, 2);
         assert_eq!(steps_to_z_2, 6);
     }
+
+    #[test]
+    fn test_empty_instructions() {
+        let error = Network::parse(&Input::new("\n\nAAA = (AAA, AAA)\n")).err().unwrap();
+
+        assert_eq!(error.to_string(), "<input>:1:1: expected R or L, found \"\"");
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let network = Network::parse(&Input::new(EXAMPLE_INPUT_2)).unwrap();
+
+        assert_eq!(network.to_dot(), "\
+digraph \"network\" {
+    \"AAA\" -> \"BBB\" [label=\"L/R\"];
+    \"BBB\" -> \"AAA\" [label=\"L\"];
+    \"BBB\" -> \"ZZZ\" [label=\"R\"];
+    \"ZZZ\" -> \"ZZZ\" [label=\"L/R\"];
+}
+");
+    }
 }
 
-pub fn run_task() {
-    let network = Network::parse("input/8_1_input.txt");
+pub fn solve(network: &Network) -> u64 {
+    return network.steps_to_node("AAA", "ZZZ");
+}
 
-    let steps_to_z = network.steps_to_node("AAA", "ZZZ");
+pub fn run_task(file: &str) {
+    let day = Day8::parse_file(file).unwrap();
 
-    println!("Steps to ZZZ: {}", steps_to_z);
-}
\ No newline at end of file
+    println!("Steps to ZZZ: {}", day.part1());
+}
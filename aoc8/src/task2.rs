@@ -1,23 +1,94 @@
+use aoc_helper::Solver;
+
+use crate::Day8;
 use crate::utils::Network;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_helper::{Answer, Input};
+    use aoc_helper::cycle::Cycle;
+    use crate::utils::ZHits;
+
+    const EXAMPLE_INPUT: &str = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+";
 
     #[test]
     fn test_example_input() {
-        let network = Network::parse("../input/8_2_example_input.txt");
+        let network = Network::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
 
         let res = network.steps_to_all_z();
 
-        assert_eq!(res, 6);
+        assert_eq!(res, Ok(6));
+    }
+
+    // Ghost 1 is on 1Z after 2, 5, 8, ... steps and ghost 2 is on 2Z after 1, 3, 5, ... steps
+    const OFFSET_INPUT: &str = "\
+L
+
+1A = (1B, 1B)
+1B = (1Z, 1Z)
+1Z = (1C, 1C)
+1C = (1B, 1B)
+2A = (2Z, 2Z)
+2Z = (2B, 2B)
+2B = (2Z, 2Z)
+";
+
+    #[test]
+    fn test_offset_input() {
+        let network = Network::parse(&Input::new(OFFSET_INPUT)).unwrap();
+
+        assert_eq!(network.z_hits("1A"), Ok(ZHits { offset: 2, period: 3 }));
+        assert_eq!(network.z_hits("2A"), Ok(ZHits { offset: 1, period: 2 }));
+        assert_eq!(network.steps_to_all_z(), Ok(5));
+    }
+
+    #[test]
+    fn test_ghost_cycle() {
+        let network = Network::parse(&Input::new(EXAMPLE_INPUT)).unwrap();
+
+        assert_eq!(network.ghost_cycle("11A"), Cycle { prefix: 1, length: 2 });
+        assert_eq!(network.ghost_cycle("22A"), Cycle { prefix: 1, length: 6 });
+
+        // 22Z is passed twice per cycle, as the instructions are half way through the second time
+        assert_eq!(network.z_hits("11A"), Ok(ZHits { offset: 2, period: 2 }));
+        assert_eq!(network.z_hits("22A"), Ok(ZHits { offset: 3, period: 3 }));
+    }
+
+    #[test]
+    fn test_unsolvable_networks() {
+        let day = Day8::parse("L\n\n1A = (1B, 1B)\n1B = (1B, 1B)\n").unwrap();
+        assert_eq!(day.part2(), Answer::Unsolved);
+
+        // 1Z and 2Z are one and two steps apart in the cycle
+        let network = Network::parse(&Input::new("L\n\n1A = (1Z, 1Z)\n1Z = (2Z, 2Z)\n2Z = (1B, 1B)\n1B = (1Z, 1Z)\n")).unwrap();
+        assert_eq!(network.z_hits("1A"), Err(String::from("Ghost from 1A is on Z nodes at irregular steps [1, 2] of its cycle Cycle { prefix: 1, length: 3 }")));
+
+        // Ghost 1 is on 1Z after odd steps and ghost 2 on 2Z after even steps
+        let network = Network::parse(&Input::new("L\n\n1A = (1Z, 1Z)\n1Z = (1B, 1B)\n1B = (1Z, 1Z)\n2A = (2B, 2B)\n2B = (2Z, 2Z)\n2Z = (2B, 2B)\n")).unwrap();
+        // Note: Which congruence comes first depends on the order of the nodes
+        assert!(network.steps_to_all_z().unwrap_err().starts_with("Ghosts are never on Z nodes at the same time"));
     }
 }
 
-pub fn run_task() {
-    let network = Network::parse("input/8_1_input.txt");
+pub fn solve(network: &Network) -> Option<u64> {
+    // Note: Only networks built like the puzzle inputs can be solved, see `steps_to_all_z`
+    return network.steps_to_all_z().ok();
+}
 
-    let res = network.steps_to_all_z();
+pub fn run_task(file: &str) {
+    let day = Day8::parse_file(file).unwrap();
 
-    println!("Res: {}", res);
-}
\ No newline at end of file
+    println!("Res: {}", day.part2());
+}
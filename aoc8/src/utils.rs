@@ -1,9 +1,10 @@
 use std::collections::HashMap;
 use regex::Regex;
-use chrono;
-use std::fs::OpenOptions;
-use std::io::Write;
-use std::fs::File;
+
+use aoc_helper::{Input, ParseError};
+use aoc_helper::cycle::{self, Cycle};
+use aoc_helper::dot::Dot;
+use aoc_helper::math;
 
 #[derive(Debug)]
 struct Node {
@@ -12,6 +13,13 @@ struct Node {
     pub right: String
 }
 
+/// Steps after which a ghost is on a node ending with Z, once it walks in circles: `offset + k * period` for all k
+#[derive(Debug, PartialEq)]
+pub struct ZHits {
+    pub offset: u64,
+    pub period: u64
+}
+
 #[derive(Debug)]
 pub struct Network {
     instructions: String,
@@ -19,93 +27,91 @@ pub struct Network {
 }
 
 impl Network {
-    fn open_appendable_files(name: &str, count: u32) -> Vec<File> {
-        let mut files = Vec::new();
+    fn next_node(&self, node: &Node, instruction: char) -> &Node {
+        return match instruction {
+            'R' => self.nodes.get(&node.right).unwrap(),
+            'L' => self.nodes.get(&node.left).unwrap(),
+            _ => panic!("Instruction not R or L!")
+        };
+    }
 
-        for i in 0..count {
-            let file_name = format!("{}_{}.txt", name, i + 1);
+    /// Where the path from `start_node` starts repeating
+    ///
+    /// The path is a sequence of (node, position in instructions) states, which has to repeat as there are finitely many
+    pub fn ghost_cycle(&self, start_node: &str) -> Cycle {
+        let instructions: Vec<char> = self.instructions.chars().collect();
 
-            let mut file = OpenOptions::new()
-            .write(true)
-            .append(true)
-            .create(true)
-            .open(file_name)
-            .unwrap();
+        return cycle::brent(&(start_node, 0), |(label, i)| {
+            let node = self.next_node(self.nodes.get(*label).unwrap(), instructions[*i]);
+            (node.label.as_str(), (i + 1) % instructions.len())
+        });
+    }
 
-            files.push(file);
+    // The nodes on the first `steps` steps of the path from `start_node`, starting with `start_node` itself
+    fn path(&self, start_node: &str, steps: usize) -> Vec<&Node> {
+        let mut node = self.nodes.get(start_node).unwrap();
+        let mut path = Vec::with_capacity(steps);
+
+        for c in self.instructions.chars().cycle().take(steps) {
+            path.push(node);
+            node = self.next_node(node, c);
         }
 
-        return files;
+        return path;
     }
 
+    /// When the ghost starting at `start_node` is on a node ending with Z, once it walks in circles
+    ///
+    /// Gives an error if it isn't on Z nodes at regular intervals within its cycle (e.g. a single Z node per cycle)
+    pub fn z_hits(&self, start_node: &str) -> Result<ZHits, String> {
+        let cycle = self.ghost_cycle(start_node);
+        let path = self.path(start_node, cycle.prefix + cycle.length);
 
-    /// Calculcate steps from start nodes to Z node (simultaneously)
-    /// 
-    /// Actual steps to come up with the answer (not what is implemented)
-    /// 1. Save nodes visited to file (one file per start node/moving entity)
-    /// 2. Realize nodes visited follow a pattern that is reapeated every X_i * len(instructions) (i=0..len(nodes))
-    /// 3. Realize that there is only one node ending with Z for each moving entity
-    ///    *) This node is repeated with X_i * len(instructions) (i=0..len(nodes))
-    /// 4. 1: JVZ steps=19783 (= 73 * 271)
-    ///    2: MRZ steps=18157 (= 67 * 271)
-    ///    3: TVZ steps=19241 (= 71 * 271)
-    ///    4: KMZ steps=14363 (= 53 * 271)
-    ///    5: ZZZ steps=15989 (= 59 * 271)
-    ///    6: RCZ steps=12737 (= 47 * 271)
-    /// 5. Find LCM of (73, 67, 71, 53, 47) = (as they are all prime numbers) 73 * 67 * 71 * 53 * 47 = 51 036 601 909
-    /// 6. Steps = LCM(above) * 271 = 13 830 919 117 339
-    pub fn steps_to_all_z(&self) -> u64 {
-        let mut steps = 0;
-        let mut max_z_nodes = 0;
-
-        // Start nodes
-        let mut nodes: Vec<&Node> = self.nodes.iter().filter(|(_, node)| node.label.ends_with('A')).map(|(_, node)| node).collect();
-
-        let mut files = Self::open_appendable_files("output", nodes.len() as u32);
+        let hits: Vec<usize> = (cycle.prefix..path.len()).filter(|i| path[*i].label.ends_with('Z')).collect();
+        if hits.is_empty() {
+            return Err(format!("Ghost from {} never walks in circles through a Z node", start_node));
+        }
 
-        loop {
-            for c in self.instructions.chars() {
-                nodes = nodes.iter().map(|node| {
-                    if c == 'R' {
-                        self.nodes.get(&node.right).unwrap()
-                    } else if c == 'L' {
-                        self.nodes.get(&node.left).unwrap()
-                    } else {
-                        panic!("Instruction not R or L!")
-                    }
-                }).collect();
-
-                // TODO: remove (save to files)
-                for (node, file) in nodes.iter().zip(files.iter_mut()) {
-                    if let Err(e) = writeln!(file, "{}", node.label) {
-                        eprintln!("Couldn't write to file: {}", e);
-                    }
-                }
-                
-                steps += 1;
+        // Note: Hits evenly spread over the cycle are also `period` apart when wrapping around to the next cycle
+        let period = cycle.length / hits.len();
+        if !cycle.length.is_multiple_of(hits.len()) || hits.windows(2).any(|pair| pair[1] - pair[0] != period) {
+            return Err(format!("Ghost from {} is on Z nodes at irregular steps {:?} of its cycle {:?}", start_node, hits, cycle));
+        }
 
-                // Compare all nodes end label char with Z!
-                let z_nodes = nodes.iter().filter(|node| node.label.ends_with('Z')).count();
-                
-                if z_nodes > max_z_nodes {
-                    println!("{:?}", chrono::offset::Local::now());
-                    println!("Seen {} matchin z nodes", z_nodes);
-                    max_z_nodes = z_nodes;
-                }
+        return Ok(ZHits { offset: hits[0] as u64, period: period as u64 });
+    }
 
-                // TODO: Revert
-                // if z_nodes == nodes.len() {
-                //     return steps;
-                // }
-                
-                // TODO: Remove
-                if steps == 500000 {
-                    panic!()
-                }
-            }
+    /// Calculcate steps from start nodes to Z node (simultaneously)
+    ///
+    /// Every ghost ends up walking in circles, so the steps at which all of them are on Z nodes are found from the
+    /// periods of their [`ZHits`]. That's the LCM of the periods if they are on Z nodes at multiples of their period,
+    /// otherwise the Chinese remainder theorem is needed
+    ///
+    /// Gives an error for networks that are not built like this, see [`Network::z_hits`]
+    pub fn steps_to_all_z(&self) -> Result<u64, String> {
+        let start_nodes: Vec<&str> = self.nodes.keys().filter(|label| label.ends_with('A')).map(|label| label.as_str()).collect();
+        let hits: Vec<ZHits> = start_nodes.iter().map(|label| self.z_hits(label)).collect::<Result<_, _>>()?;
+        let max_offset = hits.iter().map(|hit| hit.offset).max().unwrap_or(0);
+        let too_many_steps = || format!("The ghosts need more than {} steps", u64::MAX);
+
+        // Before all of them walk in circles, ghosts could be on Z nodes that are never visited again
+        let paths: Vec<Vec<&Node>> = start_nodes.iter().map(|label| self.path(label, max_offset as usize)).collect();
+        if let Some(steps) = (1..max_offset as usize).find(|i| paths.iter().all(|path| path[*i].label.ends_with('Z'))) {
+            return Ok(steps as u64);
         }
 
-        return 0; // TODO: Remove
+        let (steps, period) = if hits.iter().all(|hit| hit.offset.is_multiple_of(hit.period)) {
+            (0, math::checked_lcm_all(hits.iter().map(|hit| hit.period)).ok_or_else(too_many_steps)?)
+        } else {
+            let congruences = hits.iter().map(|hit| (hit.offset as i128, hit.period as i128));
+            let (steps, period) = math::crt(congruences).map_err(|e| format!("Ghosts are never on Z nodes at the same time: {}", e))?;
+            (u64::try_from(steps).map_err(|_| too_many_steps())?, u64::try_from(period).map_err(|_| too_many_steps())?)
+        };
+
+        // The smallest solution might be before all ghosts are on their first Z node
+        return max_offset.saturating_sub(steps).div_ceil(period).checked_mul(period)
+            .and_then(|wait| steps.checked_add(wait))
+            .ok_or_else(too_many_steps);
     }
 
     pub fn steps_to_node(&self, start_node: &str, end_node: &str) -> u64 {
@@ -114,14 +120,7 @@ impl Network {
         
         loop {
             for c in self.instructions.chars() {
-                node = 
-                    if c == 'R' {
-                        self.nodes.get(&node.right).unwrap()
-                    } else if c == 'L' {
-                        self.nodes.get(&node.left).unwrap()
-                    } else {
-                        panic!("Instruction not R or L!")
-                    };
+                node = self.next_node(node, c);
 
                 steps += 1;
 
@@ -132,24 +131,61 @@ impl Network {
         }
     }
 
-    pub fn parse(file: &str) -> Network {
-        let lines = aoc_helper::read_lines(file);
+    /// The network in the DOT language of Graphviz, edges are labelled with the instruction that follows them
+    pub fn to_dot(&self) -> String {
+        let mut dot = Dot::directed("network");
+
+        let mut labels: Vec<&String> = self.nodes.keys().collect();
+        labels.sort();
+
+        for label in labels {
+            let node = &self.nodes[label];
+            if node.left == node.right {
+                dot.edge(label, &node.left, &[("label", "L/R")]);
+            } else {
+                dot.edge(label, &node.left, &[("label", "L")]);
+                dot.edge(label, &node.right, &[("label", "R")]);
+            }
+        }
+
+        return dot.finish();
+    }
+
+    pub fn parse(input: &Input) -> Result<Network, ParseError> {
+        let lines = input.lines();
         let mut it = lines.iter();
 
-        let instructions = it.next().unwrap().to_string();
+        let instructions_line = it.next().ok_or_else(|| input.end("instructions"))?;
+        if instructions_line.text.is_empty() {
+            return Err(instructions_line.end("R or L"));
+        }
+        if let Some(i) = instructions_line.text.find(|c| c != 'R' && c != 'L') {
+            return Err(instructions_line.error(&instructions_line.text[i..], "R or L"));
+        }
+        let instructions = instructions_line.text.to_string();
 
         it.next(); // Skip empty line
 
         let mut nodes = HashMap::new();
-        let re = Regex::new(r"([^\s]+) = \(([^\s]+), ([^\s]+)\)").unwrap();
+        let mut node_lines = Vec::new();
+        let re = Regex::new(r"^([^\s]+) = \(([^\s]+), ([^\s]+)\)$").unwrap();
         while let Some(line) = it.next() {
-            for (_, [node_label, node_left_label, node_right_label]) in re.captures_iter(line).map(|c| c.extract()) {
-                let node = Node { label: node_label.to_string(), left: node_left_label.to_string(), right: node_right_label.to_string() };
-                nodes.insert(node.label.to_string(), node);
-                break;
+            let captures = re.captures(line.text).ok_or_else(|| line.error(line.text, "<node> = (<left>, <right>)"))?;
+            let (_, [node_label, node_left_label, node_right_label]) = captures.extract();
+            let node = Node { label: node_label.to_string(), left: node_left_label.to_string(), right: node_right_label.to_string() };
+            nodes.insert(node.label.to_string(), node);
+            node_lines.push((line, node_left_label, node_right_label));
+        }
+
+        // All nodes that can be reached must also be defined
+        for (line, node_left_label, node_right_label) in node_lines {
+            for label in [node_left_label, node_right_label] {
+                if !nodes.contains_key(label) {
+                    return Err(line.error(label, "a defined node"));
+                }
             }
         }
 
-        return Network { instructions: instructions, nodes: nodes }
+        return Ok(Network { instructions: instructions, nodes: nodes })
     }
-}
\ No newline at end of file
+}
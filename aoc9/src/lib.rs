@@ -0,0 +1,32 @@
+pub mod task1;
+pub mod task2;
+pub mod utils;
+
+use aoc_helper::{Answer, Input, ParseError, Solver};
+
+use crate::utils::Oasis;
+
+pub struct Day9 {
+    oasis: Oasis
+}
+
+impl Solver for Day9 {
+    fn parse_input(input: &Input) -> Result<Self, ParseError> {
+        return Ok(Day9 { oasis: Oasis::parse_all(input)? });
+    }
+
+    fn part1(&self) -> Answer {
+        return task1::solve(&self.oasis).into();
+    }
+
+    fn part2(&self) -> Answer {
+        return task2::solve(&self.oasis).into();
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE_INPUT: &str = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+";
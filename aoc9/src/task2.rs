@@ -1,12 +1,17 @@
+use aoc_helper::Solver;
+
+use crate::Day9;
 use crate::utils::Oasis;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_helper::Input;
+    use crate::EXAMPLE_INPUT;
 
     #[test]
     fn test_example_input() {
-        let oasis = Oasis::parse_all("../input/9_1_example_input.txt");
+        let oasis = Oasis::parse_all(&Input::new(EXAMPLE_INPUT)).unwrap();
         
         let extrapolated_values = oasis.extrapolate_all(true);
 
@@ -17,10 +22,14 @@ mod tests {
     }
 }
 
-pub fn run_task() {
-    let oasis = Oasis::parse_all("input/9_1_input.txt");
+pub fn solve(oasis: &Oasis) -> i64 {
     let extrapolated_values = oasis.extrapolate_all(true);
-    let sum_ext_vals = extrapolated_values.iter().sum::<i64>();
-        
-    println!("Sum backwards extrapolated values: {}", sum_ext_vals);
-}
\ No newline at end of file
+
+    return extrapolated_values.iter().sum::<i64>();
+}
+
+pub fn run_task(file: &str) {
+    let day = Day9::parse_file(file).unwrap();
+
+    println!("Sum backwards extrapolated values: {}", day.part2());
+}
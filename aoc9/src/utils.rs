@@ -1,3 +1,5 @@
+use aoc_helper::{Input, Line, ParseError};
+
 #[derive(Clone)]
 struct HistoryValue {
     values: Vec<i64>
@@ -39,10 +41,10 @@ impl HistoryValue {
         return Self::extrapolate_rec(&self.values);
     }
 
-    fn parse(line: &str) -> HistoryValue {
-        let values = line.split(" ").map(|s| s.parse::<i64>().unwrap()).collect();
+    fn parse(line: &Line) -> Result<HistoryValue, ParseError> {
+        let values = line.text.split(" ").map(|s| line.parse::<i64>(s, "a number")).collect::<Result<_, _>>()?;
 
-        HistoryValue { values: values }
+        Ok(HistoryValue { values: values })
     }
 }
 
@@ -61,10 +63,9 @@ impl Oasis {
         history_values.iter().map(|hv| hv.extrapolate()).collect()
     } 
 
-    pub fn parse_all(file: &str) -> Oasis {
-        let lines = aoc_helper::read_lines(file);
-        let history_values = lines.iter().map(|line| HistoryValue::parse(line)).collect();
+    pub fn parse_all(input: &Input) -> Result<Oasis, ParseError> {
+        let history_values = input.lines().iter().map(|line| HistoryValue::parse(line)).collect::<Result<_, _>>()?;
 
-        Oasis { history_values: history_values }
+        Ok(Oasis { history_values: history_values })
     }
 }
\ No newline at end of file
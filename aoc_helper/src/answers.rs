@@ -0,0 +1,186 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+
+use crate::Answer;
+
+/// Error produced when the answers file can not be read or written
+#[derive(Debug)]
+pub enum AnswersError {
+    Io { file: String, source: io::Error },
+    Invalid { file: String, message: String },
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Io { file, source } => write!(f, "{}: {}", file, source),
+            AnswersError::Invalid { file, message } => write!(f, "{}: {}", file, message),
+        }
+    }
+}
+
+impl Error for AnswersError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        return match self {
+            AnswersError::Io { source, .. } => Some(source),
+            _ => None
+        };
+    }
+}
+
+/// Outcome of comparing an answer with the confirmed one
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    /// Same as the confirmed answer
+    Pass,
+    /// Differs from the confirmed answer
+    Changed { expected: Answer },
+    /// There is no confirmed answer yet
+    Unconfirmed,
+}
+
+/// Confirmed answers per day and part, stored as TOML:
+///
+/// ```toml
+/// [day1]
+/// part1 = 142
+/// part2 = 281
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, u8), Answer>
+}
+
+impl Answers {
+    /// Load the answers file, a missing file has no answers
+    pub fn load(file: &str) -> Result<Answers, AnswersError> {
+        return match fs::read_to_string(file) {
+            Ok(content) => Self::parse(&content).map_err(|message| AnswersError::Invalid { file: file.to_owned(), message: message }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(source) => Err(AnswersError::Io { file: file.to_owned(), source: source })
+        };
+    }
+
+    pub fn save(&self, file: &str) -> Result<(), AnswersError> {
+        return fs::write(file, self.to_toml()).map_err(|source| AnswersError::Io { file: file.to_owned(), source: source });
+    }
+
+    pub fn parse(content: &str) -> Result<Answers, String> {
+        let table: toml::Table = content.parse().map_err(|err: toml::de::Error| err.message().to_owned())?;
+        let mut answers = Answers::default();
+
+        for (day_key, parts) in table {
+            let day = Self::parse_key(&day_key, "day", 25)?;
+            let parts = parts.as_table().ok_or_else(|| format!("[{}] must be a table", day_key))?;
+
+            for (part_key, value) in parts {
+                let part = Self::parse_key(part_key, "part", 2)?;
+                let answer = match value {
+                    toml::Value::Integer(number) => Answer::Number(*number),
+                    toml::Value::String(text) => Answer::Text(text.clone()),
+                    _ => return Err(format!("{}.{} must be an integer or a string", day_key, part_key))
+                };
+
+                answers.insert(day, part, answer);
+            }
+        }
+
+        return Ok(answers);
+    }
+
+    // Parse e.g. "day12" into 12
+    fn parse_key(key: &str, prefix: &str, max: u8) -> Result<u8, String> {
+        return key.strip_prefix(prefix)
+            .and_then(|number| number.parse::<u8>().ok())
+            .filter(|number| (1..=max).contains(number))
+            .ok_or_else(|| format!("expected {}1 to {}{}, found {:?}", prefix, prefix, max, key));
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut toml = String::new();
+        let mut last_day = None;
+
+        for ((day, part), answer) in &self.answers {
+            if last_day != Some(*day) {
+                if last_day.is_some() {
+                    toml.push('\n');
+                }
+                toml.push_str(&format!("[day{}]\n", day));
+                last_day = Some(*day);
+            }
+
+            let value = match answer {
+                Answer::Number(number) => toml::Value::Integer(*number),
+                _ => toml::Value::String(answer.to_string())
+            };
+            toml.push_str(&format!("part{} = {}\n", part, value));
+        }
+
+        return toml;
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        return self.answers.get(&(day, part));
+    }
+
+    /// Record a confirmed answer, unsolved answers are never recorded
+    pub fn insert(&mut self, day: u8, part: u8, answer: Answer) {
+        if answer != Answer::Unsolved {
+            self.answers.insert((day, part), answer);
+        }
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &Answer) -> Verdict {
+        return match self.get(day, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Changed { expected: expected.clone() },
+            None => Verdict::Unconfirmed
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = "\
+[day2]
+part1 = 8
+part2 = 2286
+
+[day10]
+part1 = \"ABC\"
+";
+
+    #[test]
+    fn test_round_trip() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+
+        assert_eq!(answers.get(2, 2), Some(&Answer::Number(2286)));
+        assert_eq!(answers.get(10, 1), Some(&Answer::Text(String::from("ABC"))));
+        assert_eq!(answers.get(10, 2), None);
+        assert_eq!(answers.to_toml(), ANSWERS);
+    }
+
+    #[test]
+    fn test_check() {
+        let mut answers = Answers::parse(ANSWERS).unwrap();
+
+        assert_eq!(answers.check(2, 1, &Answer::Number(8)), Verdict::Pass);
+        assert_eq!(answers.check(2, 1, &Answer::Number(9)), Verdict::Changed { expected: Answer::Number(8) });
+        assert_eq!(answers.check(3, 1, &Answer::Number(8)), Verdict::Unconfirmed);
+
+        answers.insert(3, 1, Answer::Unsolved);
+        assert_eq!(answers.check(3, 1, &Answer::Number(8)), Verdict::Unconfirmed);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(Answers::parse("[day26]\npart1 = 1").unwrap_err(), "expected day1 to day25, found \"day26\"");
+        assert_eq!(Answers::parse("[day1]\npart3 = 1").unwrap_err(), "expected part1 to part2, found \"part3\"");
+        assert_eq!(Answers::parse("[day1]\npart1 = 1.5").unwrap_err(), "day1.part1 must be an integer or a string");
+    }
+}
@@ -0,0 +1,192 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Input, ParseError, Solver};
+
+/// How often a part is run before and while being measured
+#[derive(Debug, Clone, Copy)]
+pub struct BenchConfig {
+    pub warmups: u32,
+    pub iterations: u32
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        return BenchConfig { warmups: 3, iterations: 10 };
+    }
+}
+
+/// Summary of the measured run times, in nanoseconds
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut samples = samples.to_vec();
+        samples.sort();
+
+        return Stats {
+            min_ns: Self::percentile(&samples, 0) as u64,
+            median_ns: Self::percentile(&samples, 50) as u64,
+            p95_ns: Self::percentile(&samples, 95) as u64
+        };
+    }
+
+    // Nearest-rank percentile of sorted samples
+    fn percentile(sorted: &[Duration], percent: usize) -> u128 {
+        let rank = (percent * sorted.len()).div_ceil(100).max(1);
+
+        return sorted[rank - 1].as_nanos();
+    }
+
+    pub fn min(&self) -> Duration {
+        return Duration::from_nanos(self.min_ns);
+    }
+
+    pub fn median(&self) -> Duration {
+        return Duration::from_nanos(self.median_ns);
+    }
+
+    pub fn p95(&self) -> Duration {
+        return Duration::from_nanos(self.p95_ns);
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "min {:.2?}, median {:.2?}, p95 {:.2?}", self.min(), self.median(), self.p95())
+    }
+}
+
+/// Benchmark of one part of a day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub iterations: u32,
+    pub parse: Stats,
+    pub solve: Stats
+}
+
+fn measure<T>(config: &BenchConfig, mut fun: impl FnMut() -> T) -> Stats {
+    for _ in 0..config.warmups {
+        fun();
+    }
+
+    let mut samples = Vec::new();
+    for _ in 0..config.iterations.max(1) {
+        let before = Instant::now();
+        let result = fun();
+        samples.push(before.elapsed());
+        drop(result);
+    }
+
+    return Stats::from_samples(&samples);
+}
+
+/// Benchmark parsing the input and solving the part separately
+pub fn bench<S: Solver>(day: u8, part: u8, input: &Input, config: &BenchConfig) -> Result<Measurement, ParseError> {
+    let solver = S::parse_input(input)?;
+    let answer = if part == 1 { solver.part1() } else { solver.part2() };
+
+    let parse = measure(config, || S::parse_input(input));
+    let solve = measure(config, || if part == 1 { solver.part1() } else { solver.part2() });
+
+    return Ok(Measurement {
+        day: day,
+        part: part,
+        answer: answer.to_string(),
+        iterations: config.iterations.max(1),
+        parse: parse,
+        solve: solve
+    });
+}
+
+pub fn to_json(measurements: &[Measurement]) -> String {
+    return serde_json::to_string_pretty(measurements).unwrap();
+}
+
+pub fn to_csv(measurements: &[Measurement]) -> String {
+    let mut csv = String::from("day,part,answer,iterations,parse_min_ns,parse_median_ns,parse_p95_ns,solve_min_ns,solve_median_ns,solve_p95_ns\n");
+
+    for m in measurements {
+        // Note: Answers are numbers or plain text, but quote them in case they ever contain a comma
+        csv.push_str(&format!(
+            "{},{},\"{}\",{},{},{},{},{},{},{}\n",
+            m.day, m.part, m.answer.replace('"', "\"\""), m.iterations,
+            m.parse.min_ns, m.parse.median_ns, m.parse.p95_ns,
+            m.solve.min_ns, m.solve.median_ns, m.solve.p95_ns
+        ));
+    }
+
+    return csv;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Answer;
+
+    use super::*;
+
+    struct Sum {
+        numbers: Vec<i64>
+    }
+
+    impl Solver for Sum {
+        fn parse_input(input: &Input) -> Result<Self, ParseError> {
+            let mut numbers = Vec::new();
+            for line in input.lines() {
+                numbers.push(line.parse(line.text, "a number")?);
+            }
+
+            return Ok(Sum { numbers: numbers });
+        }
+
+        fn part1(&self) -> Answer {
+            return self.numbers.iter().sum::<i64>().into();
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let samples: Vec<Duration> = (1..=20).rev().map(Duration::from_nanos).collect();
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats, Stats { min_ns: 1, median_ns: 10, p95_ns: 19 });
+        assert_eq!(Stats::from_samples(&[Duration::from_nanos(7)]), Stats { min_ns: 7, median_ns: 7, p95_ns: 7 });
+    }
+
+    #[test]
+    fn test_bench() {
+        let input = Input::new("1\n2\n3");
+        let config = BenchConfig { warmups: 1, iterations: 5 };
+
+        let measurement = bench::<Sum>(1, 1, &input, &config).unwrap();
+        assert_eq!(measurement.answer, "6");
+        assert_eq!(measurement.iterations, 5);
+        assert!(measurement.parse.min_ns <= measurement.parse.median_ns && measurement.parse.median_ns <= measurement.parse.p95_ns);
+
+        let measurement_2 = bench::<Sum>(1, 2, &input, &config).unwrap();
+        assert_eq!(measurement_2.answer, "unsolved");
+
+        assert!(bench::<Sum>(1, 1, &Input::new("x"), &config).is_err());
+    }
+
+    #[test]
+    fn test_formats() {
+        let stats = Stats { min_ns: 1, median_ns: 2, p95_ns: 3 };
+        let measurements = vec![Measurement { day: 5, part: 2, answer: String::from("46"), iterations: 10, parse: stats, solve: stats }];
+
+        assert_eq!(to_csv(&measurements).lines().nth(1).unwrap(), "5,2,\"46\",10,1,2,3,1,2,3");
+
+        let parsed: Vec<Measurement> = serde_json::from_str(&to_json(&measurements)).unwrap();
+        assert_eq!(parsed, measurements);
+    }
+}
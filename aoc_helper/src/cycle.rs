@@ -0,0 +1,190 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where the states `start, step(start), step(step(start)), ...` start repeating
+///
+/// The state after `prefix + length` steps is the same as the state after `prefix` steps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// Steps before the first state that is part of the cycle
+    pub prefix: usize,
+    pub length: usize
+}
+
+impl Cycle {
+    /// The lowest number of steps that reaches the same state as `n` steps
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.prefix {
+            return n;
+        }
+
+        return self.prefix + (n - self.prefix) % self.length;
+    }
+
+    /// The state after `n` steps, only stepping `reduce(n)` times
+    pub fn state_at<S: Clone>(&self, start: &S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+        let mut state = start.clone();
+        for _ in 0..self.reduce(n) {
+            state = step(&state);
+        }
+
+        return state;
+    }
+}
+
+/// Brent's algorithm, only keeps two states in memory
+///
+/// Never returns if the states don't repeat
+pub fn brent<S: Clone + PartialEq>(start: &S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // The hare searches for the tortoise in windows of doubling size, until it finds it within a window
+    let mut window = 1;
+    let mut length = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(start);
+    while tortoise != hare {
+        if window == length {
+            tortoise = hare.clone();
+            window *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // With the hare `length` steps ahead, both first meet at the start of the cycle
+    let mut tortoise = start.clone();
+    let mut hare = start.clone();
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    return Cycle { prefix: prefix, length: length };
+}
+
+/// Floyd's algorithm (tortoise and hare), only keeps two states in memory
+///
+/// Usually steps more often than [`brent`]. Never returns if the states don't repeat
+pub fn floyd<S: Clone + PartialEq>(start: &S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // The hare moves twice as fast, so they meet after a multiple of the cycle length
+    let mut tortoise = step(start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    // Restarting the tortoise, both first meet at the start of the cycle
+    let mut tortoise = start.clone();
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    let mut length = 1;
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+
+    return Cycle { prefix: prefix, length: length };
+}
+
+/// Remember every state, so each one is only computed once
+///
+/// Returns the cycle and all states before the first repeated one, so `states[cycle.reduce(n)]` is the state after
+/// `n` steps. Never returns if the states don't repeat
+pub fn hashed<S: Clone + Eq + Hash>(start: S, step: impl FnMut(&S) -> S) -> (Cycle, Vec<S>) {
+    return match hashed_up_to(start, step, usize::MAX) {
+        Ok(found) => found,
+        Err(_) => panic!("No state repeated within {} steps", usize::MAX)
+    };
+}
+
+/// The state after `n` steps, skipping the repetitions of a cycle if one is found before
+pub fn state_at<S: Clone + Eq + Hash>(start: S, step: impl FnMut(&S) -> S, n: usize) -> S {
+    return match hashed_up_to(start, step, n) {
+        Ok((cycle, mut states)) => states.swap_remove(cycle.reduce(n)),
+        Err(state) => state
+    };
+}
+
+// Like `hashed`, but gives up with the state after `limit` steps if no state repeated by then
+fn hashed_up_to<S: Clone + Eq + Hash>(start: S, mut step: impl FnMut(&S) -> S, limit: usize) -> Result<(Cycle, Vec<S>), S> {
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = start;
+
+    while states.len() < limit {
+        match seen.entry(state.clone()) {
+            Entry::Occupied(entry) => {
+                let prefix = *entry.get();
+                return Ok((Cycle { prefix: prefix, length: states.len() - prefix }, states));
+            },
+            Entry::Vacant(entry) => {
+                entry.insert(states.len());
+            }
+        }
+
+        let next = step(&state);
+        states.push(state);
+        state = next;
+    }
+
+    return Err(state);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, 3, 4, 2, 3, 4, ...
+    fn rho(n: &u32) -> u32 {
+        return if *n == 4 { 2 } else { n + 1 };
+    }
+
+    #[test]
+    fn test_cycle() {
+        let expected = Cycle { prefix: 2, length: 3 };
+
+        assert_eq!(brent(&0, rho), expected);
+        assert_eq!(floyd(&0, rho), expected);
+        assert_eq!(hashed(0, rho), (expected, vec![0, 1, 2, 3, 4]));
+        assert_eq!(brent(&3, rho), Cycle { prefix: 0, length: 3 });
+
+        assert_eq!(expected.reduce(1), 1);
+        assert_eq!(expected.reduce(10), 4);
+        assert_eq!(expected.state_at(&0, rho, 1_000_000_000), 4);
+    }
+
+    #[test]
+    fn test_algorithms_agree() {
+        let square = |n: &u64| (n * n + 1) % 1009;
+
+        for start in 0..1009 {
+            let (cycle, states) = hashed(start, square);
+            assert_eq!(brent(&start, square), cycle);
+            assert_eq!(floyd(&start, square), cycle);
+            assert_eq!(square(states.last().unwrap()), states[cycle.prefix]);
+        }
+    }
+
+    #[test]
+    fn test_state_at() {
+        assert_eq!(state_at(0, rho, 3), 3);
+        assert_eq!(state_at(0, rho, 11), 2);
+        // Stops before a cycle is found
+        assert_eq!(state_at(0u64, |n| n + 1, 1000), 1000);
+    }
+}
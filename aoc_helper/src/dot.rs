@@ -0,0 +1,88 @@
+use std::fmt::Write;
+
+/// Writes a graph in the DOT language of Graphviz, e.g. to render it with `dot -Tsvg`
+///
+/// All ids and attribute values are quoted, so any names can be used
+pub struct Dot {
+    directed: bool,
+    out: String
+}
+
+impl Dot {
+    /// Graph with arrows, `a -> b`
+    pub fn directed(name: &str) -> Dot {
+        return Dot::new("digraph", name, true);
+    }
+
+    /// Graph with lines, `a -- b`
+    pub fn undirected(name: &str) -> Dot {
+        return Dot::new("graph", name, false);
+    }
+
+    fn new(keyword: &str, name: &str, directed: bool) -> Dot {
+        return Dot { directed: directed, out: format!("{} {} {{\n", keyword, quote(name)) };
+    }
+
+    /// Add a node, only needed to give it attributes as edges add their nodes as well
+    pub fn node(&mut self, id: &str, attributes: &[(&str, &str)]) {
+        writeln!(self.out, "    {}{};", quote(id), format_attributes(attributes)).unwrap();
+    }
+
+    pub fn edge(&mut self, from: &str, to: &str, attributes: &[(&str, &str)]) {
+        let arrow = if self.directed { "->" } else { "--" };
+        writeln!(self.out, "    {} {} {}{};", quote(from), arrow, quote(to), format_attributes(attributes)).unwrap();
+    }
+
+    pub fn finish(mut self) -> String {
+        self.out.push_str("}\n");
+
+        return self.out;
+    }
+}
+
+fn quote(text: &str) -> String {
+    return format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""));
+}
+
+fn format_attributes(attributes: &[(&str, &str)]) -> String {
+    if attributes.is_empty() {
+        return String::new();
+    }
+
+    let attributes: Vec<String> = attributes.iter().map(|(key, value)| format!("{}={}", key, quote(value))).collect();
+
+    return format!(" [{}]", attributes.join(", "));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_directed() {
+        let mut dot = Dot::directed("pulses");
+        dot.node("a", &[("shape", "box")]);
+        dot.edge("a", "b", &[]);
+        dot.edge("b", "a", &[("label", "high"), ("color", "red")]);
+
+        assert_eq!(dot.finish(), "\
+digraph \"pulses\" {
+    \"a\" [shape=\"box\"];
+    \"a\" -> \"b\";
+    \"b\" -> \"a\" [label=\"high\", color=\"red\"];
+}
+");
+    }
+
+    #[test]
+    fn test_undirected_and_quoting() {
+        let mut dot = Dot::undirected("say \"hi\"");
+        dot.edge("(1, 2)", "back\\slash", &[("label", "5")]);
+
+        assert_eq!(dot.finish(), "\
+graph \"say \\\"hi\\\"\" {
+    \"(1, 2)\" -- \"back\\\\slash\" [label=\"5\"];
+}
+");
+    }
+}
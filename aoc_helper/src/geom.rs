@@ -0,0 +1,281 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+/// One of the 4 orthogonal directions
+///
+/// The y axis points down like the rows of the input, so north is `(0, -1)`. Discriminants count clockwise from
+/// north, so a direction can be used as index with `direction as usize`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North = 0,
+    East = 1,
+    South = 2,
+    West = 3
+}
+
+/// One of the 8 orthogonal and diagonal directions, counting clockwise from north
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    North = 0,
+    NorthEast = 1,
+    East = 2,
+    SouthEast = 3,
+    South = 4,
+    SouthWest = 5,
+    West = 6,
+    NorthWest = 7
+}
+
+/// Signed 2D vector, e.g. an offset between positions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Vec2 {
+    pub x: isize,
+    pub y: isize
+}
+
+/// Position in a grid, see [`crate::grid::Grid`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Pos {
+    pub x: usize,
+    pub y: usize
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    /// Parse a compass (`NESW`), relative (`URDL`) or arrow (`^>v<`) direction
+    pub fn parse(c: char) -> Option<Direction> {
+        return match c {
+            'N' | 'U' | '^' => Some(Direction::North),
+            'E' | 'R' | '>' => Some(Direction::East),
+            'S' | 'D' | 'v' => Some(Direction::South),
+            'W' | 'L' | '<' => Some(Direction::West),
+            _ => None
+        };
+    }
+
+    /// Arrow pointing in this direction, as accepted by [`Direction::parse`]
+    pub fn arrow(self) -> char {
+        return match self {
+            Direction::North => '^',
+            Direction::East => '>',
+            Direction::South => 'v',
+            Direction::West => '<'
+        };
+    }
+
+    pub fn turn_left(self) -> Direction {
+        return Self::ALL[(self as usize + 3) % 4];
+    }
+
+    pub fn turn_right(self) -> Direction {
+        return Self::ALL[(self as usize + 1) % 4];
+    }
+
+    pub fn reverse(self) -> Direction {
+        return Self::ALL[(self as usize + 2) % 4];
+    }
+
+    pub fn is_vertical(self) -> bool {
+        return self == Direction::North || self == Direction::South;
+    }
+
+    pub fn offset(self) -> Vec2 {
+        return Direction8::from(self).offset();
+    }
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North, Direction8::NorthEast, Direction8::East, Direction8::SouthEast,
+        Direction8::South, Direction8::SouthWest, Direction8::West, Direction8::NorthWest
+    ];
+
+    /// Turn 45 degrees counterclockwise
+    pub fn turn_left(self) -> Direction8 {
+        return Self::ALL[(self as usize + 7) % 8];
+    }
+
+    /// Turn 45 degrees clockwise
+    pub fn turn_right(self) -> Direction8 {
+        return Self::ALL[(self as usize + 1) % 8];
+    }
+
+    pub fn reverse(self) -> Direction8 {
+        return Self::ALL[(self as usize + 4) % 8];
+    }
+
+    pub fn offset(self) -> Vec2 {
+        let (x, y) = match self {
+            Direction8::North => (0, -1),
+            Direction8::NorthEast => (1, -1),
+            Direction8::East => (1, 0),
+            Direction8::SouthEast => (1, 1),
+            Direction8::South => (0, 1),
+            Direction8::SouthWest => (-1, 1),
+            Direction8::West => (-1, 0),
+            Direction8::NorthWest => (-1, -1)
+        };
+
+        return Vec2::new(x, y);
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Direction8 {
+        return Direction8::ALL[direction as usize * 2];
+    }
+}
+
+impl Vec2 {
+    pub fn new(x: isize, y: isize) -> Vec2 {
+        return Vec2 { x: x, y: y };
+    }
+
+    /// Length in steps when only moving orthogonally
+    pub fn manhattan(&self) -> usize {
+        return self.x.unsigned_abs() + self.y.unsigned_abs();
+    }
+
+    /// The position this vector points to from the origin, if it is not negative
+    pub fn to_pos(self) -> Option<Pos> {
+        return match (usize::try_from(self.x), usize::try_from(self.y)) {
+            (Ok(x), Ok(y)) => Some(Pos::new(x, y)),
+            _ => None
+        };
+    }
+}
+
+impl Pos {
+    pub fn new(x: usize, y: usize) -> Pos {
+        return Pos { x: x, y: y };
+    }
+
+    /// `None` if the result would be left of or above the origin
+    pub fn checked_add(self, offset: Vec2) -> Option<Pos> {
+        return (Vec2::from(self) + offset).to_pos();
+    }
+
+    /// Add `offset`, wrapping around inside a `width` x `height` area
+    pub fn wrapping_add(self, offset: Vec2, width: usize, height: usize) -> Pos {
+        let moved = Vec2::from(self) + offset;
+
+        return Pos::new(moved.x.rem_euclid(width as isize) as usize, moved.y.rem_euclid(height as isize) as usize);
+    }
+
+    /// Neighbouring position in `direction`, if it is inside a `width` x `height` area
+    pub fn step(self, direction: Direction, width: usize, height: usize) -> Option<Pos> {
+        return self.checked_add(direction.offset()).filter(|pos| pos.x < width && pos.y < height);
+    }
+
+    pub fn manhattan(self, other: Pos) -> usize {
+        return self.x.abs_diff(other.x) + self.y.abs_diff(other.y);
+    }
+}
+
+impl From<(usize, usize)> for Pos {
+    /// From an `(x, y)` tuple, as used by [`crate::grid::Grid`]
+    fn from((x, y): (usize, usize)) -> Pos {
+        return Pos::new(x, y);
+    }
+}
+
+impl From<Pos> for Vec2 {
+    fn from(pos: Pos) -> Vec2 {
+        return Vec2::new(pos.x as isize, pos.y as isize);
+    }
+}
+
+impl fmt::Display for Pos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, other: Vec2) -> Vec2 {
+        return Vec2::new(self.x + other.x, self.y + other.y);
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, other: Vec2) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, other: Vec2) -> Vec2 {
+        return Vec2::new(self.x - other.x, self.y - other.y);
+    }
+}
+
+impl Mul<isize> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, factor: isize) -> Vec2 {
+        return Vec2::new(self.x * factor, self.y * factor);
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        return Vec2::new(-self.x, -self.y);
+    }
+}
+
+impl Sub for Pos {
+    type Output = Vec2;
+
+    /// Offset from `other` to `self`
+    fn sub(self, other: Pos) -> Vec2 {
+        return Vec2::from(self) - Vec2::from(other);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+        assert_eq!(Direction::East.reverse(), Direction::West);
+        assert!(Direction::ALL.iter().all(|d| d.turn_left().turn_left() == d.reverse()));
+
+        assert_eq!(Direction8::North.turn_left(), Direction8::NorthWest);
+        assert_eq!(Direction8::SouthWest.reverse(), Direction8::NorthEast);
+        assert_eq!(Direction8::from(Direction::South), Direction8::South);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("NESW".chars().map(Direction::parse).collect::<Vec<_>>(), Direction::ALL.map(Some));
+        assert_eq!("URDL".chars().map(Direction::parse).collect::<Vec<_>>(), Direction::ALL.map(Some));
+        assert_eq!("^>v<".chars().map(Direction::parse).collect::<Vec<_>>(), Direction::ALL.map(Some));
+        assert_eq!(Direction::parse('x'), None);
+        assert!(Direction::ALL.iter().all(|d| Direction::parse(d.arrow()) == Some(*d)));
+    }
+
+    #[test]
+    fn test_positions() {
+        let pos = Pos::new(1, 0);
+
+        assert_eq!(pos.checked_add(Direction::West.offset()), Some(Pos::new(0, 0)));
+        assert_eq!(pos.checked_add(Direction::North.offset()), None);
+        assert_eq!(pos.step(Direction::East, 2, 2), None);
+        assert_eq!(pos.step(Direction::South, 2, 2), Some(Pos::new(1, 1)));
+        assert_eq!(pos.wrapping_add(Vec2::new(-3, -1), 3, 2), Pos::new(1, 1));
+        assert_eq!(Pos::new(5, 1).manhattan(Pos::new(1, 3)), 6);
+        assert_eq!(Pos::new(5, 1) - Pos::new(1, 3), Vec2::new(4, -2));
+        assert_eq!((Direction::East.offset() * 3 + Direction8::NorthWest.offset()).manhattan(), 3);
+        assert_eq!(-Vec2::new(1, -2), Vec2::new(-1, 2));
+    }
+}
@@ -0,0 +1,313 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::geom::{Direction, Direction8, Pos, Vec2};
+use crate::{Input, Line, ParseError};
+
+/// Rectangular 2D grid, stored row by row
+///
+/// Positions are `(x, y)` with `(0, 0)` in the top left corner. Indexing with a single number gives a whole
+/// row, so `grid[y][x]` works like it does for `Vec<Vec<T>>`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        return Grid { width: width, height: height, cells: vec![fill; width * height] };
+    }
+
+    /// Rows become columns, i.e. mirrored along the diagonal from the top left corner
+    pub fn transpose(&self) -> Grid<T> {
+        return self.rebuild(self.height, self.width, |x, y| (y, x));
+    }
+
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        return self.rebuild(self.height, self.width, |x, y| (y, self.height - 1 - x));
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Grid<T> {
+        return self.rebuild(self.height, self.width, |x, y| (self.width - 1 - y, x));
+    }
+
+    /// Surround the grid with `n` rows and columns of `fill` on every side
+    pub fn padded(&self, n: usize, fill: T) -> Grid<T> {
+        let mut grid = Grid::new(self.width + 2 * n, self.height + 2 * n, fill);
+        for (pos, value) in self.iter() {
+            grid[(pos.0 + n, pos.1 + n)] = value.clone();
+        }
+
+        return grid;
+    }
+
+    // New grid of the given size, where `source` maps each new position to the position it is copied from
+    fn rebuild(&self, width: usize, height: usize, source: impl Fn(usize, usize) -> (usize, usize)) -> Grid<T> {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(self[source(x, y)].clone());
+            }
+        }
+
+        return Grid { width: width, height: height, cells: cells };
+    }
+}
+
+impl<T> Grid<T> {
+    /// Gives [`ParseError::RaggedRow`] if the rows are not all equally long
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, ParseError> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(ParseError::RaggedRow { row: y + 1, expected: width, found: row.len() });
+            }
+            cells.extend(row);
+        }
+
+        return Ok(Grid { width: width, height: height, cells: cells });
+    }
+
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    pub fn height(&self) -> usize {
+        return self.height;
+    }
+
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        return x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height;
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        return if x < self.width && y < self.height { Some(&self.cells[y * self.width + x]) } else { None };
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        return if x < self.width && y < self.height { Some(&mut self.cells[y * self.width + x]) } else { None };
+    }
+
+    /// Like [`Grid::get`], but positions left of or above the grid are `None` too
+    pub fn get_signed(&self, x: isize, y: isize) -> Option<&T> {
+        return if self.contains(x, y) { self.get(x as usize, y as usize) } else { None };
+    }
+
+    /// Get a cell of the grid repeated infinitely in every direction
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        return &self[(x.rem_euclid(self.width as isize) as usize, y.rem_euclid(self.height as isize) as usize)];
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        return &self.cells[y * self.width..(y + 1) * self.width];
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        return &mut self.cells[y * self.width..(y + 1) * self.width];
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        return (0..self.height).map(|y| self.row(y));
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        return (0..self.height).map(move |y| &self[(x, y)]);
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        return (0..self.width).map(|x| self.column(x));
+    }
+
+    /// All positions, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+
+        return (0..self.width * self.height).map(move |i| (i % width, i / width));
+    }
+
+    /// All cells with their positions, row by row
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        return self.positions().zip(self.cells.iter());
+    }
+
+    pub fn values(&self) -> std::slice::Iter<'_, T> {
+        return self.cells.iter();
+    }
+
+    pub fn values_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        return self.cells.iter_mut();
+    }
+
+    /// Position of the first cell (row by row) matching `predicate`
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        return self.cells.iter().position(predicate).map(|i| (i % self.width, i / self.width));
+    }
+
+    /// Orthogonal neighbours inside the grid, clockwise starting north
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        return self.neighbours(x, y, Direction::ALL.map(Direction::offset));
+    }
+
+    /// Orthogonal and diagonal neighbours inside the grid, clockwise starting north
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        return self.neighbours(x, y, Direction8::ALL.map(Direction8::offset));
+    }
+
+    fn neighbours<const N: usize>(&self, x: usize, y: usize, offsets: [Vec2; N]) -> impl Iterator<Item = (usize, usize)> + '_ {
+        return offsets.into_iter()
+            .map(move |offset| (x as isize + offset.x, y as isize + offset.y))
+            .filter(|(x, y)| self.contains(*x, *y))
+            .map(|(x, y)| (x as usize, y as usize));
+    }
+
+    /// Neighbouring position in `direction`, if it is inside the grid
+    pub fn step(&self, pos: Pos, direction: Direction) -> Option<Pos> {
+        return pos.step(direction, self.width, self.height);
+    }
+
+    pub fn map<U>(&self, fun: impl FnMut(&T) -> U) -> Grid<U> {
+        return Grid { width: self.width, height: self.height, cells: self.cells.iter().map(fun).collect() };
+    }
+}
+
+impl Grid<char> {
+    /// Parse the whole input as a grid, where every char must be one of `allowed`
+    pub fn parse(input: &Input, allowed: &str) -> Result<Grid<char>, ParseError> {
+        return Grid::from_rows(input.char_grid(allowed)?);
+    }
+
+    /// Parse some lines of an input as a grid, see [`Grid::parse`]
+    pub fn parse_lines(lines: &[Line], allowed: &str) -> Result<Grid<char>, ParseError> {
+        return Grid::from_rows(crate::char_grid(lines, allowed)?);
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        if x >= self.width || y >= self.height {
+            panic!("Position ({}, {}) is outside of the {}x{} grid", x, y, self.width, self.height);
+        }
+
+        return &self.cells[y * self.width + x];
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        if x >= self.width || y >= self.height {
+            panic!("Position ({}, {}) is outside of the {}x{} grid", x, y, self.width, self.height);
+        }
+
+        return &mut self.cells[y * self.width + x];
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        return &self[(pos.x, pos.y)];
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        return &mut self[(pos.x, pos.y)];
+    }
+}
+
+impl<T> Index<usize> for Grid<T> {
+    type Output = [T];
+
+    fn index(&self, y: usize) -> &[T] {
+        return self.row(y);
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, y: usize) -> &mut [T] {
+        return self.row_mut(y);
+    }
+}
+
+/// One line per row, cells printed next to each other
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        return Grid::parse(&Input::new("abc\ndef"), "abcdef").unwrap();
+    }
+
+    #[test]
+    fn test_parse_and_index() {
+        let grid = grid();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 0)], 'c');
+        assert_eq!(grid[1][0], 'd');
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get_signed(-1, 0), None);
+        assert_eq!(grid.get_signed(1, 1), Some(&'e'));
+        assert_eq!(grid.get_wrapping(-1, 2), &'c');
+        assert_eq!(grid.position(|c| *c == 'e'), Some((1, 1)));
+        assert!(Grid::parse(&Input::new("ab\nc"), "abc").is_err());
+
+        assert_eq!(Grid::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap()[(0, 1)], 3);
+        assert_eq!(Grid::from_rows(Vec::<Vec<u8>>::new()).unwrap().width(), 0);
+        assert_eq!(Grid::from_rows(vec![vec![1, 2], vec![3, 4], vec![5]]).unwrap_err().to_string(), "row 3: expected a row of width 2, found width 1");
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = grid();
+
+        assert_eq!(grid.rows().map(|row| row.iter().collect()).collect::<Vec<String>>(), vec!["abc", "def"]);
+        assert_eq!(grid.columns().map(|column| column.collect()).collect::<Vec<String>>(), vec!["ad", "be", "cf"]);
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &'e')));
+    }
+
+    #[test]
+    fn test_transformations() {
+        let grid = grid();
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+        assert_eq!(grid.padded(1, '.').to_string(), ".....\n.abc.\n.def.\n.....\n");
+        assert_eq!(grid.map(|c| c.to_ascii_uppercase()).to_string(), "ABC\nDEF\n");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+
+        assert_eq!(grid.neighbours4(1, 1).collect::<Vec<_>>(), vec![(1, 0), (2, 1), (1, 2), (0, 1)]);
+        assert_eq!(grid.neighbours4(0, 0).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours8(1, 1).count(), 8);
+        assert_eq!(grid.neighbours8(2, 2).collect::<Vec<_>>(), vec![(2, 1), (1, 2), (1, 1)]);
+        assert_eq!(grid.step(Pos::new(2, 1), Direction::East), None);
+        assert_eq!(grid.step(Pos::new(2, 1), Direction::West), Some(Pos::new(1, 1)));
+    }
+}
@@ -0,0 +1,218 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+
+use serde::{Deserialize, Serialize};
+
+use crate::bench::{Measurement, Stats};
+
+/// Error produced when the history file can not be read or written
+#[derive(Debug)]
+pub enum HistoryError {
+    Io { file: String, source: io::Error },
+    Invalid { file: String, line: usize, message: String },
+}
+
+impl fmt::Display for HistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HistoryError::Io { file, source } => write!(f, "{}: {}", file, source),
+            HistoryError::Invalid { file, line, message } => write!(f, "{}:{}: {}", file, line, message),
+        }
+    }
+}
+
+impl Error for HistoryError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        return match self {
+            HistoryError::Io { source, .. } => Some(source),
+            _ => None
+        };
+    }
+}
+
+/// Benchmark results of one run, `commit` is suffixed with "-dirty" for uncommitted changes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Run {
+    pub commit: String,
+    pub timestamp: String,
+    pub measurements: Vec<Measurement>
+}
+
+/// All saved runs, stored as one JSON object per line so saving a run only appends
+#[derive(Debug, Default)]
+pub struct History {
+    runs: Vec<Run>
+}
+
+impl History {
+    /// Load the history file, a missing file has no runs
+    pub fn load(file: &str) -> Result<History, HistoryError> {
+        let content = match fs::read_to_string(file) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(History::default()),
+            Err(source) => return Err(HistoryError::Io { file: file.to_owned(), source: source })
+        };
+
+        let mut runs = Vec::new();
+        for (i, line) in content.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+            let run = serde_json::from_str(line).map_err(|err| HistoryError::Invalid { file: file.to_owned(), line: i + 1, message: err.to_string() })?;
+            runs.push(run);
+        }
+
+        return Ok(History { runs: runs });
+    }
+
+    pub fn append(file: &str, run: &Run) -> Result<(), HistoryError> {
+        let io_error = |source| HistoryError::Io { file: file.to_owned(), source: source };
+        let mut history = OpenOptions::new().create(true).append(true).open(file).map_err(io_error)?;
+
+        return writeln!(history, "{}", serde_json::to_string(run).unwrap()).map_err(io_error);
+    }
+
+    pub fn runs(&self) -> &Vec<Run> {
+        return &self.runs;
+    }
+
+    /// Commits with saved runs, oldest first
+    pub fn commits(&self) -> Vec<&str> {
+        let mut commits: Vec<&str> = Vec::new();
+
+        for run in &self.runs {
+            commits.retain(|commit| *commit != run.commit);
+            commits.push(&run.commit);
+        }
+
+        return commits;
+    }
+
+    /// The most recently run commit starting with `prefix`
+    pub fn find_commit(&self, prefix: &str) -> Option<&str> {
+        return self.commits().into_iter().rev().find(|commit| commit.starts_with(prefix));
+    }
+
+    /// Latest measurement of every day and part run at `commit`
+    pub fn measurements(&self, commit: &str) -> BTreeMap<(u8, u8), &Measurement> {
+        let mut measurements = BTreeMap::new();
+
+        for run in self.runs.iter().filter(|run| run.commit == commit) {
+            for measurement in &run.measurements {
+                measurements.insert((measurement.day, measurement.part), measurement);
+            }
+        }
+
+        return measurements;
+    }
+}
+
+/// Timings of a day's part at two commits
+#[derive(Debug, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: u8,
+    pub base: (Stats, Stats),
+    pub head: (Stats, Stats)
+}
+
+impl Comparison {
+    /// Relative change of the median in percent
+    pub fn change(base: &Stats, head: &Stats) -> f64 {
+        return (head.median_ns as f64 - base.median_ns as f64) / (base.median_ns.max(1) as f64) * 100.0;
+    }
+
+    /// Stages ("parse" and/or "solve") whose median got slower by more than `threshold` percent
+    pub fn regressions(&self, threshold: f64) -> Vec<&'static str> {
+        let mut stages = Vec::new();
+
+        if Self::change(&self.base.0, &self.head.0) > threshold {
+            stages.push("parse");
+        }
+        if Self::change(&self.base.1, &self.head.1) > threshold {
+            stages.push("solve");
+        }
+
+        return stages;
+    }
+}
+
+/// Compare the days and parts measured at both commits
+pub fn compare(history: &History, base: &str, head: &str) -> Vec<Comparison> {
+    let base = history.measurements(base);
+    let head = history.measurements(head);
+
+    return base.iter()
+        .filter_map(|(key, b)| head.get(key).map(|h| Comparison {
+            day: key.0,
+            part: key.1,
+            base: (b.parse, b.solve),
+            head: (h.parse, h.solve)
+        }))
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    fn measurement(day: u8, part: u8, parse_ns: u64, solve_ns: u64) -> Measurement {
+        let stats = |ns| Stats { min_ns: ns, median_ns: ns, p95_ns: ns };
+
+        return Measurement { day: day, part: part, answer: String::from("1"), iterations: 1, parse: stats(parse_ns), solve: stats(solve_ns) };
+    }
+
+    fn run(commit: &str, measurements: Vec<Measurement>) -> Run {
+        return Run { commit: commit.to_owned(), timestamp: String::from("2023-12-24T12:00:00+00:00"), measurements: measurements };
+    }
+
+    #[test]
+    fn test_append_and_load() {
+        let file = env::temp_dir().join(format!("aoc_history_{}.jsonl", std::process::id())).to_string_lossy().into_owned();
+        let _ = fs::remove_file(&file);
+
+        assert!(History::load(&file).unwrap().runs().is_empty());
+
+        let runs = vec![run("aaa111", vec![measurement(1, 1, 10, 100)]), run("bbb222", vec![measurement(1, 1, 10, 90)])];
+        for r in &runs {
+            History::append(&file, r).unwrap();
+        }
+        assert_eq!(History::load(&file).unwrap().runs(), &runs);
+
+        fs::remove_file(&file).unwrap();
+    }
+
+    #[test]
+    fn test_latest_measurement_wins() {
+        let history = History { runs: vec![
+            run("aaa111", vec![measurement(1, 1, 10, 100), measurement(1, 2, 10, 100)]),
+            run("bbb222", vec![measurement(1, 1, 10, 100)]),
+            run("aaa111", vec![measurement(1, 1, 10, 50)]),
+        ] };
+
+        assert_eq!(history.commits(), vec!["bbb222", "aaa111"]);
+        assert_eq!(history.find_commit("aaa"), Some("aaa111"));
+        assert_eq!(history.find_commit("ccc"), None);
+
+        let measurements = history.measurements("aaa111");
+        assert_eq!(measurements.len(), 2);
+        assert_eq!(measurements[&(1, 1)].solve.median_ns, 50);
+    }
+
+    #[test]
+    fn test_compare() {
+        let history = History { runs: vec![
+            run("base", vec![measurement(5, 1, 100, 1000), measurement(5, 2, 100, 1000), measurement(6, 1, 100, 100)]),
+            run("head", vec![measurement(5, 1, 100, 1050), measurement(5, 2, 150, 2000)]),
+        ] };
+
+        let comparisons = compare(&history, "base", "head");
+        assert_eq!(comparisons.len(), 2);
+        assert_eq!(comparisons[0].regressions(10.0), Vec::<&str>::new());
+        assert_eq!(comparisons[0].regressions(4.0), vec!["solve"]);
+        assert_eq!(comparisons[1].regressions(10.0), vec!["parse", "solve"]);
+        assert_eq!(Comparison::change(&comparisons[1].base.1, &comparisons[1].head.1), 100.0);
+    }
+}
@@ -0,0 +1,258 @@
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Environment variable overriding the cache directory
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+/// Environment variable overriding the base URL inputs are fetched from
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+/// Environment variable holding the session cookie of the logged in user
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Error produced when an input can not be fetched or cached
+#[derive(Debug)]
+pub enum FetchError {
+    /// The server answered, but not with the input
+    Status { url: String, status: u16 },
+    /// The server could not be reached
+    Transport { url: String, message: String },
+    /// The cache could not be written
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::Status { url, status } => write!(f, "{}: server responded with status {}", url, status),
+            FetchError::Transport { url, message } => write!(f, "{}: {}", url, message),
+            FetchError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+        }
+    }
+}
+
+impl Error for FetchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        return match self {
+            FetchError::Io { source, .. } => Some(source),
+            _ => None
+        };
+    }
+}
+
+/// Something that can get the puzzle input of a day
+pub trait Fetcher {
+    fn fetch(&self, day: u8) -> Result<String, FetchError>;
+}
+
+/// Fetches inputs over HTTP from `{base_url}/2023/day/{day}/input`
+pub struct HttpFetcher {
+    base_url: String,
+    session: Option<String>
+}
+
+impl HttpFetcher {
+    pub fn new(base_url: &str, session: Option<&str>) -> HttpFetcher {
+        return HttpFetcher { base_url: base_url.trim_end_matches('/').to_owned(), session: session.map(String::from) };
+    }
+
+    /// Configure from `AOC_BASE_URL` and `AOC_SESSION`
+    pub fn from_env() -> HttpFetcher {
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_owned());
+        let session = env::var(SESSION_VAR).ok();
+
+        return HttpFetcher::new(&base_url, session.as_deref());
+    }
+
+    pub fn url(&self, day: u8) -> String {
+        return format!("{}/2023/day/{}/input", self.base_url, day);
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, day: u8) -> Result<String, FetchError> {
+        let url = self.url(day);
+        let mut request = ureq::get(&url).set("User-Agent", "aoc_2023 input cache");
+        if let Some(session) = &self.session {
+            request = request.set("Cookie", &format!("session={}", session));
+        }
+
+        return match request.call() {
+            Ok(response) => response.into_string().map_err(|err| FetchError::Transport { url: url.clone(), message: err.to_string() }),
+            Err(ureq::Error::Status(status, _)) => Err(FetchError::Status { url: url, status: status }),
+            Err(err) => Err(FetchError::Transport { url: url, message: err.to_string() })
+        };
+    }
+}
+
+/// Per-user cache of puzzle inputs, each day is fetched at most once
+///
+/// Files are named like the ones in the input directory, so the cache directory can also be used as `AOC_INPUT_DIR`
+pub struct InputCache<F: Fetcher> {
+    dir: PathBuf,
+    fetcher: F
+}
+
+impl InputCache<HttpFetcher> {
+    pub fn from_env() -> InputCache<HttpFetcher> {
+        return InputCache::new(Self::default_dir(), HttpFetcher::from_env());
+    }
+
+    /// `AOC_CACHE_DIR`, or else `aoc_2023` in the user's cache directory
+    pub fn default_dir() -> PathBuf {
+        if let Some(dir) = env::var_os(CACHE_DIR_VAR) {
+            return PathBuf::from(dir);
+        }
+
+        let base = env::var_os("XDG_CACHE_HOME").map(PathBuf::from)
+            .or_else(|| env::var_os("LOCALAPPDATA").map(PathBuf::from))
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+            .unwrap_or_else(env::temp_dir);
+
+        return base.join("aoc_2023");
+    }
+}
+
+impl<F: Fetcher> InputCache<F> {
+    pub fn new(dir: PathBuf, fetcher: F) -> InputCache<F> {
+        return InputCache { dir: dir, fetcher: fetcher };
+    }
+
+    pub fn dir(&self) -> &Path {
+        return &self.dir;
+    }
+
+    /// Path of the cached input, whether it exists or not
+    ///
+    /// Both parts of a day share the same input
+    pub fn path(&self, day: u8) -> PathBuf {
+        return self.dir.join(format!("{}_1_input.txt", day));
+    }
+
+    /// Path of the cached input, if it has been fetched before
+    pub fn cached(&self, day: u8) -> Option<String> {
+        let path = self.path(day);
+
+        return if path.is_file() { Some(path.to_string_lossy().into_owned()) } else { None };
+    }
+
+    /// Path of the cached input, fetching it first if it is not cached yet
+    pub fn get(&self, day: u8) -> Result<String, FetchError> {
+        if let Some(path) = self.cached(day) {
+            return Ok(path);
+        }
+
+        let content = self.fetcher.fetch(day)?;
+        let path = self.path(day);
+        let io_error = |source| FetchError::Io { path: path.clone(), source: source };
+
+        // Note: Written to a temporary file first, so an interrupted write is never mistaken for a cached input
+        let tmp_path = path.with_extension("tmp");
+        fs::create_dir_all(&self.dir).map_err(io_error)?;
+        fs::write(&tmp_path, content).map_err(io_error)?;
+        fs::rename(&tmp_path, &path).map_err(io_error)?;
+
+        return Ok(path.to_string_lossy().into_owned());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    use super::*;
+
+    struct CountingFetcher {
+        calls: Cell<u32>
+    }
+
+    impl Fetcher for CountingFetcher {
+        fn fetch(&self, day: u8) -> Result<String, FetchError> {
+            self.calls.set(self.calls.get() + 1);
+            return Ok(format!("input of day {}\n", day));
+        }
+    }
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc_inputs_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        return dir;
+    }
+
+    /// Serve `responses` (status, body) to one connection each, returning the request head of every connection
+    fn mock_server(responses: Vec<(u16, &'static str)>) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                    request.push_str(&line);
+                }
+                requests.push(request);
+
+                write!(stream, "HTTP/1.1 {} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body).unwrap();
+            }
+
+            return requests;
+        });
+
+        return (base_url, handle);
+    }
+
+    #[test]
+    fn test_cache_never_refetches() {
+        let dir = test_dir("refetch");
+        let cache = InputCache::new(dir.clone(), CountingFetcher { calls: Cell::new(0) });
+
+        assert_eq!(cache.cached(3), None);
+        let path = cache.get(3).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "input of day 3\n");
+        assert_eq!(cache.get(3).unwrap(), path);
+        assert_eq!(cache.cached(3), Some(path));
+        assert_eq!(cache.fetcher.calls.get(), 1);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_http_fetcher() {
+        let (base_url, server) = mock_server(vec![(200, "1 2 3\n"), (404, "Not found")]);
+        let dir = test_dir("http");
+        let cache = InputCache::new(dir.clone(), HttpFetcher::new(&format!("{}/", base_url), Some("abc")));
+
+        let path = cache.get(9).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n");
+        cache.get(9).unwrap();
+
+        let err = cache.get(10).unwrap_err();
+        assert!(matches!(err, FetchError::Status { status: 404, .. }));
+        assert_eq!(cache.cached(10), None);
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].starts_with("GET /2023/day/9/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("session=abc"));
+        assert!(requests[1].starts_with("GET /2023/day/10/input HTTP/1.1\r\n"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -4,9 +4,31 @@ use std::fs::read_to_string;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 
-/// Benchmark a function
+pub mod answers;
+pub mod bench;
+pub mod cycle;
+pub mod dot;
+pub mod geom;
+pub mod grid;
+pub mod history;
+pub mod inputs;
+pub mod math;
+pub mod parallel;
+pub mod search;
+// Note: Only for tests of the days, which enable the `testing` feature in their dev-dependencies
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+mod locate;
+mod parse;
+mod solver;
+
+pub use locate::{input_path, workspace_root, InputLocator, InputNotFound, INPUT_DIR_VAR};
+pub use parse::{char_grid, Input, Line, ParseError};
+pub use solver::{Answer, Solver};
+
+/// Benchmark a function (a single run)
 /// 
-/// The result is automatically printed
+/// The result is automatically printed, see [`bench`] for repeated runs with statistics
 pub fn benchmark(fun: fn() -> ()) {
     let before = Instant::now();
     fun();
@@ -18,19 +40,19 @@ pub fn print_time_now() {
     println!("{:?}", chrono::offset::Local::now());
 }
 
+/// Get all lines in a string
+pub fn lines(content: &str) -> Vec<String> {
+    content.lines().map(String::from).collect()
+}
+
 /// Get all lines in a file
 pub fn read_lines(file: &str) -> Vec<String> {
-    read_to_string(file).unwrap().lines().map(String::from).collect()
+    lines(&read_to_string(file).unwrap())
 }
 
 /// Get all lines in a file (as double ended queue)
 pub fn read_lines_deque(file: &str) -> VecDeque<String> {
-    read_to_string(file).unwrap().lines().map(String::from).collect()
-}
-
-// TODO: Remove?
-pub fn get_input_path(day: u32, task: u32, example: bool) -> String {
-    return format!("input/{}_{}_{}input.txt", day, task, if example {"example_"} else {""})
+    lines(&read_to_string(file).unwrap()).into()
 }
 
 /// Hash an object
@@ -0,0 +1,129 @@
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Environment variable overriding the input directory
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Error produced when none of the candidate input files exist
+#[derive(Debug)]
+pub struct InputNotFound {
+    pub day: u8,
+    pub part: u8,
+    pub tried: Vec<PathBuf>
+}
+
+impl fmt::Display for InputNotFound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "No input found for day {} part {}, tried:", self.day, self.part)?;
+        for path in &self.tried {
+            write!(f, "\n  {}", path.display())?;
+        }
+
+        return Ok(());
+    }
+}
+
+impl Error for InputNotFound {}
+
+/// Root of the workspace, independent of the working directory
+pub fn workspace_root() -> PathBuf {
+    // Note: aoc_helper lives directly in the workspace root
+    return Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf();
+}
+
+/// Finds the input file of a day's part in the input directory
+pub struct InputLocator {
+    dir: PathBuf
+}
+
+impl InputLocator {
+    /// Look for inputs in `dir`, or if not given in `AOC_INPUT_DIR`, or else in `input/` of the workspace root
+    pub fn new(dir: Option<&str>) -> InputLocator {
+        let dir = match dir {
+            Some(dir) => PathBuf::from(dir),
+            None => match env::var_os(INPUT_DIR_VAR) {
+                Some(dir) => PathBuf::from(dir),
+                None => workspace_root().join("input")
+            }
+        };
+
+        return InputLocator { dir: dir };
+    }
+
+    pub fn dir(&self) -> &Path {
+        return &self.dir;
+    }
+
+    /// Candidate paths for a day's part, most specific first
+    ///
+    /// Many days reuse the part 1 input for part 2, so part 2 falls back to `{day}_1_input.txt`
+    pub fn candidates(&self, day: u8, part: u8) -> Vec<PathBuf> {
+        return (1..=part).rev().map(|p| self.dir.join(format!("{}_{}_input.txt", day, p))).collect();
+    }
+
+    pub fn locate(&self, day: u8, part: u8) -> Result<String, InputNotFound> {
+        let tried = self.candidates(day, part);
+
+        return match tried.iter().find(|path| path.is_file()) {
+            Some(path) => Ok(path.to_string_lossy().into_owned()),
+            None => Err(InputNotFound { day: day, part: part, tried: tried })
+        };
+    }
+}
+
+/// Get the path of a day's input using the default [`InputLocator`]
+///
+/// Panics listing the paths tried if there is no input
+pub fn input_path(day: u8, part: u8) -> String {
+    return match InputLocator::new(None).locate(day, part) {
+        Ok(path) => path,
+        Err(err) => panic!("{}", err)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc_locate_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        return dir;
+    }
+
+    #[test]
+    fn test_part_2_falls_back_to_part_1() {
+        let dir = test_dir("fallback");
+        fs::write(dir.join("5_1_input.txt"), "").unwrap();
+        let locator = InputLocator::new(dir.to_str());
+
+        assert_eq!(locator.locate(5, 1).unwrap(), dir.join("5_1_input.txt").to_string_lossy());
+        assert_eq!(locator.locate(5, 2).unwrap(), dir.join("5_1_input.txt").to_string_lossy());
+
+        fs::write(dir.join("5_2_input.txt"), "").unwrap();
+        assert_eq!(locator.locate(5, 2).unwrap(), dir.join("5_2_input.txt").to_string_lossy());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_not_found_lists_tried_paths() {
+        let dir = test_dir("missing");
+        let locator = InputLocator::new(dir.to_str());
+
+        let err = locator.locate(7, 2).unwrap_err();
+        assert_eq!(err.tried, vec![dir.join("7_2_input.txt"), dir.join("7_1_input.txt")]);
+        assert_eq!(
+            err.to_string(),
+            format!("No input found for day 7 part 2, tried:\n  {}\n  {}", dir.join("7_2_input.txt").display(), dir.join("7_1_input.txt").display())
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
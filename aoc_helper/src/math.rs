@@ -0,0 +1,173 @@
+use std::error::Error;
+use std::fmt;
+
+/// Error produced when a system of congruences can not be solved
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CrtError {
+    /// Moduli must be positive
+    InvalidModulus(i128),
+    /// No number is `a1 (mod m1)` and `a2 (mod m2)`, where the first congruence combines all earlier ones
+    NoSolution { a1: i128, m1: i128, a2: i128, m2: i128 },
+    /// The combined modulus does not fit in an `i128`
+    Overflow
+}
+
+impl fmt::Display for CrtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrtError::InvalidModulus(m) => write!(f, "Modulus {} is not positive", m),
+            CrtError::NoSolution { a1, m1, a2, m2 } => write!(f, "No number is {} (mod {}) and {} (mod {})", a1, m1, a2, m2),
+            CrtError::Overflow => write!(f, "The combined modulus is too large"),
+        }
+    }
+}
+
+impl Error for CrtError {}
+
+pub fn gcd(a: u64, b: u64) -> u64 {
+    return if b == 0 { a } else { gcd(b, a % b) };
+}
+
+/// Panics if the result does not fit in a `u64`, see [`checked_lcm`]
+pub fn lcm(a: u64, b: u64) -> u64 {
+    return checked_lcm(a, b).unwrap_or_else(|| panic!("lcm({}, {}) overflows", a, b));
+}
+
+/// Least common multiple, `None` if it does not fit in a `u64`
+pub fn checked_lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+
+    return (a / gcd(a, b)).checked_mul(b);
+}
+
+/// Least common multiple of all values, 1 if there are none
+pub fn lcm_all(values: impl IntoIterator<Item = u64>) -> u64 {
+    return values.into_iter().fold(1, lcm);
+}
+
+/// Like [`lcm_all`], `None` if the result does not fit in a `u64`
+pub fn checked_lcm_all(values: impl IntoIterator<Item = u64>) -> Option<u64> {
+    return values.into_iter().try_fold(1, checked_lcm);
+}
+
+/// Extended Euclidean algorithm, gives `(g, x, y)` with `a * x + b * y = g` where `g` is the (non-negative) gcd
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        return if a < 0 { (-a, -1, 0) } else { (a, 1, 0) };
+    }
+
+    let (g, x, y) = extended_gcd(b, a % b);
+
+    return (g, y, x - a / b * y);
+}
+
+/// Chinese remainder theorem, solves `x = a (mod m)` for all `(a, m)` congruences
+///
+/// The moduli don't have to be coprime. Gives the smallest `x >= 0` together with the modulus of the solution (the lcm
+/// of all moduli), so all solutions are `x + k * modulus`
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Result<(i128, i128), CrtError> {
+    let mut x = 0;
+    let mut modulus = 1;
+
+    for (a, m) in congruences {
+        if m <= 0 {
+            return Err(CrtError::InvalidModulus(m));
+        }
+
+        let a = a.rem_euclid(m);
+        let (g, inverse, _) = extended_gcd(modulus, m);
+        if (a - x) % g != 0 {
+            return Err(CrtError::NoSolution { a1: x, m1: modulus, a2: a, m2: m });
+        }
+
+        // Find k with x + k * modulus = a (mod m), which is unique modulo m / g
+        let reduced = m / g;
+        let k = mul_mod(((a - x) / g).rem_euclid(reduced), inverse.rem_euclid(reduced), reduced);
+
+        let combined = (modulus / g).checked_mul(m).ok_or(CrtError::Overflow)?;
+        // Note: Can't overflow, as it is below the combined modulus
+        x += k * modulus;
+        modulus = combined;
+    }
+
+    return Ok((x, modulus));
+}
+
+// `a * b (mod m)` for `0 <= a, b < m`, without overflowing
+fn mul_mod(a: i128, b: i128, m: i128) -> i128 {
+    if let Some(product) = a.checked_mul(b) {
+        return product % m;
+    }
+
+    // Double and add, every intermediate value stays below m
+    let add_mod = |a: i128, b: i128| if a >= m - b { a - (m - b) } else { a + b };
+    let mut result = 0;
+    let mut a = a;
+    let mut b = b;
+    while b > 0 {
+        if b & 1 == 1 {
+            result = add_mod(result, a);
+        }
+        a = add_mod(a, a);
+        b >>= 1;
+    }
+
+    return result;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm_all([73 * 271, 67 * 271, 71 * 271]), 73 * 67 * 71 * 271);
+        assert_eq!(lcm_all([]), 1);
+
+        assert_eq!(checked_lcm(4, 6), Some(12));
+        assert_eq!(checked_lcm(0, 6), Some(0));
+        assert_eq!(checked_lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(checked_lcm_all([73 * 271, 67 * 271, 71 * 271]), Some(73 * 67 * 71 * 271));
+        assert_eq!(checked_lcm_all([1 << 40, 3 << 20, 5 << 30]), Some(15 << 40));
+        assert_eq!(checked_lcm_all([1 << 40, 3 << 40, 5 << 40, 7 << 40]), Some(105 << 40));
+        assert_eq!(checked_lcm_all([(1 << 40) - 1, (1 << 40) + 1, 3]), None);
+
+        for a in -20..20 {
+            for b in -20..20 {
+                let (g, x, y) = extended_gcd(a, b);
+                assert_eq!(a * x + b * y, g);
+                assert_eq!(g as u64, gcd(a.unsigned_abs() as u64, b.unsigned_abs() as u64));
+            }
+        }
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Ok((23, 105)));
+        assert_eq!(crt([(-1, 3), (0, 4)]), Ok((8, 12)));
+        // Moduli that are not coprime
+        assert_eq!(crt([(2, 6), (8, 10)]), Ok((8, 30)));
+        assert_eq!(crt([(0, 4), (0, 6), (0, 10)]), Ok((0, 60)));
+        assert_eq!(crt([]), Ok((0, 1)));
+
+        assert_eq!(crt([(1, 4), (2, 6)]), Err(CrtError::NoSolution { a1: 1, m1: 4, a2: 2, m2: 6 }));
+        assert_eq!(crt([(1, 0)]), Err(CrtError::InvalidModulus(0)));
+    }
+
+    #[test]
+    fn test_crt_large() {
+        let p = (1 << 61) - 1;
+        let q = (1 << 64) - 59;
+
+        let (x, modulus) = crt([(1, p), (-2, q)]).unwrap();
+        assert_eq!(modulus, p * q);
+        assert_eq!((x % p, x % q), (1, q - 2));
+
+        assert_eq!(crt([(1, p), (2, q), (3, 1 << 60)]), Err(CrtError::Overflow));
+    }
+}
@@ -0,0 +1,215 @@
+use std::collections::VecDeque;
+use std::env;
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+/// Environment variable setting the number of threads of the default [`Executor`]
+pub const THREADS_VAR: &str = "AOC_THREADS";
+
+// Threads set with `set_threads`, 0 if not set
+static THREADS: AtomicUsize = AtomicUsize::new(0);
+
+/// Use `threads` threads for the default [`Executor`] from now on (e.g. from a command line option), 0 to unset it
+pub fn set_threads(threads: usize) {
+    THREADS.store(threads, Ordering::Relaxed);
+}
+
+/// Threads of the default [`Executor`]: as set by [`set_threads`], or else `AOC_THREADS`, or else the number of CPUs
+pub fn threads() -> usize {
+    let threads = THREADS.load(Ordering::Relaxed);
+    if threads > 0 {
+        return threads;
+    }
+
+    if let Some(threads) = env::var(THREADS_VAR).ok().and_then(|threads| threads.parse::<usize>().ok()) {
+        if threads > 0 {
+            return threads;
+        }
+    }
+
+    return thread::available_parallelism().map(|threads| threads.get()).unwrap_or(1);
+}
+
+/// Runs a function on many items at once, on a fixed number of threads
+///
+/// Every thread starts with its own queue of items. A thread that runs out of items steals from the back of the
+/// queues of the others, so a few slow items don't leave the other threads idle. Results are always in the order of the
+/// items, no matter which thread computed them
+pub struct Executor {
+    threads: usize
+}
+
+impl Default for Executor {
+    fn default() -> Executor {
+        return Executor::new(threads());
+    }
+}
+
+impl Executor {
+    /// Executor using `threads` threads (at least one), the calling thread being one of them
+    pub fn new(threads: usize) -> Executor {
+        return Executor { threads: threads.max(1) };
+    }
+
+    pub fn threads(&self) -> usize {
+        return self.threads;
+    }
+
+    /// `f` of every item
+    pub fn map<T: Send, R: Send>(&self, items: Vec<T>, f: impl Fn(T) -> R + Sync) -> Vec<R> {
+        return self.map_init(items, || (), |_, item| f(item));
+    }
+
+    /// Like [`Executor::map`], but every thread makes a state with `init` which `f` can reuse for all its items
+    pub fn map_init<T: Send, S, R: Send>(&self, items: Vec<T>, init: impl Fn() -> S + Sync, f: impl Fn(&mut S, T) -> R + Sync) -> Vec<R> {
+        let mut results = self.execute(items, init, |state, _, item| f(state, item));
+        results.sort_unstable_by_key(|(i, _)| *i);
+
+        return results.into_iter().map(|(_, result)| result).collect();
+    }
+
+    /// The result of the first item (in the order of the items) for which `f` gives one
+    ///
+    /// Items after one that already gave a result are skipped, so `f` is not called for all items
+    pub fn find_first<T: Send, R: Send>(&self, items: Vec<T>, f: impl Fn(T) -> Option<R> + Sync) -> Option<R> {
+        let found = AtomicUsize::new(usize::MAX);
+
+        let results = self.execute(items, || (), |_, i, item| {
+            if i > found.load(Ordering::Relaxed) {
+                return None;
+            }
+
+            let result = f(item);
+            if result.is_some() {
+                found.fetch_min(i, Ordering::Relaxed);
+            }
+
+            return result;
+        });
+
+        return results.into_iter()
+            .filter_map(|(i, result)| Some((i, result?)))
+            .min_by_key(|(i, _)| *i)
+            .map(|(_, result)| result);
+    }
+
+    // Results of all items together with their index, in no particular order
+    fn execute<T: Send, S, R: Send>(&self, items: Vec<T>, init: impl Fn() -> S + Sync, f: impl Fn(&mut S, usize, T) -> R + Sync) -> Vec<(usize, R)> {
+        let workers = self.threads.min(items.len()).max(1);
+
+        // Note: Dealt out in turns, so all threads start with the first items
+        let mut queues: Vec<VecDeque<(usize, T)>> = (0..workers).map(|_| VecDeque::new()).collect();
+        for (i, item) in items.into_iter().enumerate() {
+            queues[i % workers].push_back((i, item));
+        }
+        let queues: Vec<Mutex<VecDeque<(usize, T)>>> = queues.into_iter().map(Mutex::new).collect();
+
+        let work = |worker: usize| {
+            // Note: Only made once there is an item, threads that start late might not get any
+            let mut state = None;
+            let mut results = Vec::new();
+            while let Some((i, item)) = next_item(&queues, worker) {
+                results.push((i, f(state.get_or_insert_with(&init), i, item)));
+            }
+
+            return results;
+        };
+
+        if workers == 1 {
+            return work(0);
+        }
+
+        return thread::scope(|scope| {
+            let work = &work;
+            let handles: Vec<_> = (1..workers).map(|worker| scope.spawn(move || work(worker))).collect();
+
+            let mut results = work(0);
+            for handle in handles {
+                // Note: Resumed so the original panic message is kept
+                results.extend(handle.join().unwrap_or_else(|panic| panic::resume_unwind(panic)));
+            }
+
+            return results;
+        });
+    }
+}
+
+// Next item of the worker's own queue, or else the last item of the queue of another worker
+fn next_item<T>(queues: &[Mutex<VecDeque<(usize, T)>>], worker: usize) -> Option<(usize, T)> {
+    if let Some(item) = queues[worker].lock().unwrap().pop_front() {
+        return Some(item);
+    }
+
+    for other in 1..queues.len() {
+        if let Some(item) = queues[(worker + other) % queues.len()].lock().unwrap().pop_back() {
+            return Some(item);
+        }
+    }
+
+    return None;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Barrier;
+
+    use super::*;
+
+    #[test]
+    fn test_map_keeps_order() {
+        for threads in [1, 2, 3, 8, 100] {
+            let executor = Executor::new(threads);
+
+            assert_eq!(executor.map((0..50).collect(), |n: u64| n * n), (0..50).map(|n| n * n).collect::<Vec<u64>>());
+            assert_eq!(executor.map(Vec::new(), |n: u64| n), Vec::<u64>::new());
+        }
+    }
+
+    #[test]
+    fn test_map_init() {
+        let inits = AtomicUsize::new(0);
+        let executor = Executor::new(4);
+
+        // Every thread counts the items it handled in its own state
+        let counts = executor.map_init((0..40).collect(), || { inits.fetch_add(1, Ordering::Relaxed); 0 }, |count, _: u32| { *count += 1; *count });
+
+        assert!(inits.load(Ordering::Relaxed) <= 4);
+        assert!(counts.iter().all(|count| *count >= 1 && *count <= 40));
+        assert_eq!(counts.iter().filter(|count| **count == 1).count(), inits.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn test_find_first() {
+        for threads in [1, 3, 8] {
+            let executor = Executor::new(threads);
+
+            assert_eq!(executor.find_first((0..100).collect(), |n: u32| if n % 7 == 3 { Some(n * 10) } else { None }), Some(30));
+            assert_eq!(executor.find_first((0..100).collect(), |_: u32| None::<u32>), None);
+        }
+    }
+
+    #[test]
+    fn test_work_stealing() {
+        // The first thread gets items 0 and 2, but both have to run at the same time to get past the barrier, so the
+        // second thread must steal one of them
+        let barrier = Barrier::new(2);
+        let executor = Executor::new(2);
+        let handled_by = executor.map((0..4).collect(), |n: u32| {
+            if n == 0 || n == 2 {
+                barrier.wait();
+            }
+            thread::current().id()
+        });
+
+        assert_ne!(handled_by[0], handled_by[2]);
+    }
+
+    #[test]
+    fn test_panics_are_passed_on() {
+        let result = panic::catch_unwind(|| Executor::new(3).map((0..9).collect(), |n: u32| if n == 5 { panic!("Item {}", n) } else { n }));
+
+        assert_eq!(result.unwrap_err().downcast_ref::<String>().unwrap(), "Item 5");
+    }
+}
@@ -0,0 +1,216 @@
+use std::error::Error;
+use std::fmt;
+use std::fs::read_to_string;
+use std::io::{self, Read};
+use std::str::FromStr;
+
+/// Error produced when a puzzle input can not be parsed
+#[derive(Debug)]
+pub enum ParseError {
+    /// The input file (or reader, which has no file) could not be read
+    Io { file: Option<String>, source: io::Error },
+    /// A token did not match what the parser expected
+    Unexpected { file: Option<String>, line: usize, column: usize, expected: String, found: String },
+    /// The input ended while the parser still expected something
+    UnexpectedEnd { file: Option<String>, line: usize, expected: String },
+    /// Rows of a grid are not all equally wide, `row` is counted from 1
+    RaggedRow { row: usize, expected: usize, found: usize },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Io { file, source } => write!(f, "{}: {}", file.as_deref().unwrap_or("<input>"), source),
+            ParseError::Unexpected { file, line, column, expected, found } => {
+                write!(f, "{}:{}:{}: expected {}, found {:?}", file.as_deref().unwrap_or("<input>"), line, column, expected, found)
+            },
+            ParseError::UnexpectedEnd { file, line, expected } => {
+                write!(f, "{}:{}: unexpected end of input, expected {}", file.as_deref().unwrap_or("<input>"), line, expected)
+            },
+            ParseError::RaggedRow { row, expected, found } => write!(f, "row {}: expected a row of width {}, found width {}", row, expected, found)
+        }
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        return match self {
+            ParseError::Io { source, .. } => Some(source),
+            _ => None
+        };
+    }
+}
+
+/// Puzzle input, remembering which file it was read from (if any)
+pub struct Input {
+    file: Option<String>,
+    content: String
+}
+
+impl Input {
+    /// Input held in memory, e.g. an example embedded in a test
+    pub fn new(content: &str) -> Input {
+        return Input { file: None, content: content.to_owned() };
+    }
+
+    /// Read the input file
+    pub fn read(file: &str) -> Result<Input, ParseError> {
+        return match read_to_string(file) {
+            Ok(content) => Ok(Input { file: Some(file.to_owned()), content: content }),
+            Err(source) => Err(ParseError::Io { file: Some(file.to_owned()), source: source })
+        };
+    }
+
+    /// Read the input from e.g. stdin
+    pub fn from_reader(mut reader: impl Read) -> Result<Input, ParseError> {
+        let mut content = String::new();
+
+        return match reader.read_to_string(&mut content) {
+            Ok(_) => Ok(Input { file: None, content: content }),
+            Err(source) => Err(ParseError::Io { file: None, source: source })
+        };
+    }
+
+    pub fn content(&self) -> &str {
+        return &self.content;
+    }
+
+    /// Get all lines, numbered from 1
+    pub fn lines(&self) -> Vec<Line<'_>> {
+        return self.content.lines().enumerate().map(|(i, text)| Line { file: self.file.as_deref(), number: i + 1, text: text }).collect();
+    }
+
+    /// Error for when the input ends but `expected` was still expected
+    pub fn end(&self, expected: &str) -> ParseError {
+        return ParseError::UnexpectedEnd { file: self.file.clone(), line: self.content.lines().count() + 1, expected: expected.to_owned() };
+    }
+
+    /// Parse the whole input as a grid, see [`char_grid`]
+    pub fn char_grid(&self, allowed: &str) -> Result<Vec<Vec<char>>, ParseError> {
+        let lines = self.lines();
+
+        if lines.is_empty() {
+            return Err(self.end("a grid"));
+        }
+
+        return char_grid(&lines, allowed);
+    }
+}
+
+/// Parse lines as a rectangular grid of chars, where every char must be one of `allowed`
+pub fn char_grid(lines: &[Line], allowed: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let mut grid = Vec::new();
+
+    for line in lines {
+        if let Some((i, c)) = line.text.char_indices().find(|(_, c)| !allowed.contains(*c)) {
+            return Err(line.error(&line.text[i..i + c.len_utf8()], &format!("one of {:?}", allowed)));
+        }
+
+        let row: Vec<char> = line.text.chars().collect();
+        if row.len() != lines[0].text.chars().count() {
+            return Err(line.error(line.text, &format!("a row of width {}", lines[0].text.chars().count())));
+        }
+
+        grid.push(row);
+    }
+
+    return Ok(grid);
+}
+
+/// A single line of an [`Input`]
+#[derive(Clone, Copy)]
+pub struct Line<'a> {
+    file: Option<&'a str>,
+    pub number: usize,
+    pub text: &'a str
+}
+
+impl<'a> Line<'a> {
+    /// Error for `found`, which should be a slice of this line so the column can be determined
+    pub fn error(&self, found: &str, expected: &str) -> ParseError {
+        let start = self.text.as_ptr() as usize;
+        let offset = (found.as_ptr() as usize).wrapping_sub(start);
+        let column = if offset <= self.text.len() { self.text[..offset].chars().count() + 1 } else { 1 };
+
+        return ParseError::Unexpected {
+            file: self.file.map(String::from),
+            line: self.number,
+            column: column,
+            expected: expected.to_owned(),
+            found: found.to_owned()
+        };
+    }
+
+    /// Error for when the line ends but `expected` was still expected
+    pub fn end(&self, expected: &str) -> ParseError {
+        return self.error(&self.text[self.text.len()..], expected);
+    }
+
+    /// Parse `token` (a slice of this line)
+    pub fn parse<T: FromStr>(&self, token: &str, expected: &str) -> Result<T, ParseError> {
+        return token.parse::<T>().map_err(|_| self.error(token, expected));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(content: &str) -> Input {
+        return Input { file: Some(String::from("test.txt")), content: content.to_owned() };
+    }
+
+    #[test]
+    fn test_in_memory_input() {
+        let input = Input::new("1 2\nx");
+        let lines = input.lines();
+        assert_eq!(lines.len(), 2);
+
+        let err = lines[1].parse::<u32>(lines[1].text, "a number").unwrap_err();
+        assert_eq!(err.to_string(), "<input>:2:1: expected a number, found \"x\"");
+
+        let input = Input::from_reader("1 2\nx".as_bytes()).unwrap();
+        assert_eq!(input.content(), "1 2\nx");
+    }
+
+    #[test]
+    fn test_error_position() {
+        let input = input("Game 1: 3 blue\nGame 2: x red");
+        let lines = input.lines();
+        let line = lines[1];
+        let token = &line.text[8..9];
+
+        let err = line.parse::<u32>(token, "a number").unwrap_err();
+        assert_eq!(err.to_string(), "test.txt:2:9: expected a number, found \"x\"");
+
+        let err = line.end("a color");
+        assert_eq!(err.to_string(), "test.txt:2:14: expected a color, found \"\"");
+    }
+
+    #[test]
+    fn test_unexpected_end() {
+        let input = input("seeds: 1 2\n\n");
+
+        let err = input.end("a map");
+        assert_eq!(err.to_string(), "test.txt:3: unexpected end of input, expected a map");
+    }
+
+    #[test]
+    fn test_char_grid() {
+        let grid = input("#.\n.#").char_grid("#.").unwrap();
+        assert_eq!(grid, vec![vec!['#', '.'], vec!['.', '#']]);
+
+        let err = input("#.\n.x").char_grid("#.").unwrap_err();
+        assert_eq!(err.to_string(), "test.txt:2:2: expected one of \"#.\", found \"x\"");
+
+        let err = input("#.\n.").char_grid("#.").unwrap_err();
+        assert_eq!(err.to_string(), "test.txt:2:1: expected a row of width 2, found \".\"");
+    }
+
+    #[test]
+    fn test_io_error() {
+        let err = Input::read("does/not/exist.txt").err().unwrap();
+        assert!(matches!(err, ParseError::Io { .. }));
+        assert!(err.to_string().starts_with("does/not/exist.txt: "));
+    }
+}
@@ -0,0 +1,234 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Cheapest way found from a start to a goal state
+#[derive(Debug, Clone, PartialEq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    /// All states from the start up to and including the goal
+    pub states: Vec<S>
+}
+
+// Every state reached, with the index of the state it was reached from
+struct Nodes<S> {
+    nodes: Vec<(S, Option<usize>)>
+}
+
+impl<S: Clone> Nodes<S> {
+    fn push(&mut self, state: S, parent: Option<usize>) -> usize {
+        self.nodes.push((state, parent));
+
+        return self.nodes.len() - 1;
+    }
+
+    fn path_to(&self, mut index: usize) -> Vec<S> {
+        let mut states = vec![self.nodes[index].0.clone()];
+        while let Some(parent) = self.nodes[index].1 {
+            states.push(self.nodes[parent].0.clone());
+            index = parent;
+        }
+        states.reverse();
+
+        return states;
+    }
+}
+
+/// Breadth first search, every step costs 1
+///
+/// Returns the shortest path from any of the `starts` to a state for which `is_goal` holds
+pub fn bfs<S, I>(starts: impl IntoIterator<Item = S>, mut successors: impl FnMut(&S) -> I, mut is_goal: impl FnMut(&S) -> bool) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>
+{
+    let mut nodes = Nodes { nodes: Vec::new() };
+    let mut depths: HashMap<S, usize> = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = depths.entry(start.clone()) {
+            entry.insert(0);
+            queue.push_back(nodes.push(start, None));
+        }
+    }
+
+    while let Some(index) = queue.pop_front() {
+        let state = nodes.nodes[index].0.clone();
+        if is_goal(&state) {
+            return Some(Path { cost: depths[&state], states: nodes.path_to(index) });
+        }
+
+        let depth = depths[&state] + 1;
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = depths.entry(next.clone()) {
+                entry.insert(depth);
+                queue.push_back(nodes.push(next, Some(index)));
+            }
+        }
+    }
+
+    return None;
+}
+
+/// Dijkstra's algorithm, `successors` gives the next states with the cost of moving there
+///
+/// Returns the cheapest path from any of the `starts` to a state for which `is_goal` holds
+pub fn dijkstra<S, C, I>(starts: impl IntoIterator<Item = S>, successors: impl FnMut(&S) -> I, is_goal: impl FnMut(&S) -> bool) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>
+{
+    return astar(starts, successors, |_| C::default(), is_goal);
+}
+
+/// A* search, like [`dijkstra`] but guided by `heuristic`
+///
+/// The heuristic estimates the remaining cost to a goal. It must never overestimate, otherwise the path found might
+/// not be the cheapest
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>
+{
+    let mut nodes = Nodes { nodes: Vec::new() };
+    // Cheapest known cost of every state and the node it was reached with
+    let mut best: HashMap<S, (C, usize)> = HashMap::new();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = best.entry(start.clone()) {
+            let estimate = heuristic(&start);
+            let index = nodes.push(start, None);
+            entry.insert((C::default(), index));
+            heap.push(Candidate { estimate: estimate, cost: C::default(), index: index });
+        }
+    }
+
+    while let Some(Candidate { cost, index, .. }) = heap.pop() {
+        let state = nodes.nodes[index].0.clone();
+
+        // Note: Skip states that were reached cheaper after this candidate was queued
+        if best[&state].1 != index {
+            continue;
+        }
+
+        if is_goal(&state) {
+            return Some(Path { cost: cost, states: nodes.path_to(index) });
+        }
+
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            if matches!(best.get(&next), Some((known_cost, _)) if *known_cost <= next_cost) {
+                continue;
+            }
+
+            let estimate = next_cost + heuristic(&next);
+            let next_index = nodes.push(next.clone(), Some(index));
+            best.insert(next, (next_cost, next_index));
+            heap.push(Candidate { estimate: estimate, cost: next_cost, index: next_index });
+        }
+    }
+
+    return None;
+}
+
+// Queued state, the heap pops the lowest estimate first
+struct Candidate<C> {
+    estimate: C,
+    cost: C,
+    index: usize
+}
+
+impl<C: Ord> Ord for Candidate<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Note: Reversed to turn the max-heap into a min-heap, ties prefer the most recently queued state
+        return other.estimate.cmp(&self.estimate).then(self.index.cmp(&other.index));
+    }
+}
+
+impl<C: Ord> PartialOrd for Candidate<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl<C: Ord> PartialEq for Candidate<C> {
+    fn eq(&self, other: &Self) -> bool {
+        return self.cmp(other) == Ordering::Equal;
+    }
+}
+
+impl<C: Ord> Eq for Candidate<C> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::geom::Pos;
+    use crate::grid::Grid;
+    use crate::Input;
+
+    use super::*;
+
+    const MAZE: &str = "\
+S.#....
+.##.##.
+...#...
+.#...#G
+";
+
+    fn maze() -> (Grid<char>, Pos, Pos) {
+        let grid = Grid::parse(&Input::new(MAZE), ".#SG").unwrap();
+        let start = Pos::from(grid.position(|c| *c == 'S').unwrap());
+        let goal = Pos::from(grid.position(|c| *c == 'G').unwrap());
+
+        return (grid, start, goal);
+    }
+
+    fn open_neighbours(grid: &Grid<char>, pos: &Pos) -> Vec<Pos> {
+        return grid.neighbours4(pos.x, pos.y).map(Pos::from).filter(|next| grid[*next] != '#').collect();
+    }
+
+    #[test]
+    fn test_bfs() {
+        let (grid, start, goal) = maze();
+
+        let path = bfs([start], |pos| open_neighbours(&grid, pos), |pos| *pos == goal).unwrap();
+        assert_eq!(path.cost, 11);
+        assert_eq!(path.states.len(), 12);
+        assert_eq!((path.states[0], path.states[11]), (start, goal));
+        assert!(path.states.windows(2).all(|step| step[0].manhattan(step[1]) == 1));
+
+        assert_eq!(bfs([start], |pos| open_neighbours(&grid, pos), |pos| *pos == Pos::new(2, 0)), None);
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() {
+        let (grid, start, goal) = maze();
+        // Walking on row 2 costs 5 per step
+        let successors = |pos: &Pos| open_neighbours(&grid, pos).into_iter().map(|next| (next, if next.y == 2 { 5 } else { 1 })).collect::<Vec<_>>();
+
+        let path = dijkstra([start], successors, |pos| *pos == goal).unwrap();
+        assert_eq!(path.cost, 35);
+        assert_eq!(path.states.first(), Some(&start));
+
+        let path_astar = astar([start], successors, |pos| pos.manhattan(goal), |pos| *pos == goal).unwrap();
+        assert_eq!(path_astar.cost, path.cost);
+    }
+
+    #[test]
+    fn test_multiple_starts() {
+        let successors = |n: &u32| vec![(n + 1, 1), (n * 2, 1)];
+
+        let path = dijkstra([1, 5], successors, |n| *n == 12).unwrap();
+        assert_eq!(path, Path { cost: 2, states: vec![5, 6, 12] });
+    }
+}
@@ -0,0 +1,109 @@
+use std::error::Error;
+use std::fmt;
+use std::io::Read;
+
+use crate::{Input, ParseError};
+
+/// Answer to one part of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($int:ty),+) => {
+        $(impl From<$int> for Answer {
+            fn from(value: $int) -> Self {
+                return Answer::Number(i64::try_from(value).expect("Answer does not fit in an i64"));
+            }
+        })+
+    };
+}
+
+answer_from_int!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        return Answer::Text(value);
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        return Answer::Text(value.to_owned());
+    }
+}
+
+/// `None` for inputs that a part has no answer for
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(value: Option<T>) -> Self {
+        return match value {
+            Some(value) => value.into(),
+            None => Answer::Unsolved
+        };
+    }
+}
+
+/// A day's puzzle, parsed once and then solved part by part
+pub trait Solver: Sized {
+    fn parse_input(input: &Input) -> Result<Self, ParseError>;
+
+    /// Parse puzzle input held in memory
+    fn parse(content: &str) -> Result<Self, ParseError> {
+        return Self::parse_input(&Input::new(content));
+    }
+
+    /// Parse the puzzle input found at the given path
+    fn parse_file(file: &str) -> Result<Self, ParseError> {
+        return Self::parse_input(&Input::read(file)?);
+    }
+
+    fn parse_reader(reader: impl Read) -> Result<Self, ParseError> {
+        return Self::parse_input(&Input::from_reader(reader)?);
+    }
+
+    fn part1(&self) -> Answer;
+
+    /// Days without a second part (day 25) can rely on this default
+    fn part2(&self) -> Answer {
+        return Answer::Unsolved;
+    }
+
+    /// Parse the input file and solve the given part (1 or 2)
+    fn solve(file: &str, part: u8) -> Result<Answer, Box<dyn Error>> {
+        let solver = Self::parse_file(file)?;
+
+        return match part {
+            1 => Ok(solver.part1()),
+            2 => Ok(solver.part2()),
+            _ => Err(format!("No such part: {}", part).into()),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_from() {
+        assert_eq!(Answer::from(42u64), Answer::Number(42));
+        assert_eq!(Answer::from(-3), Answer::Number(-3));
+        assert_eq!(Answer::from("ABC"), Answer::Text(String::from("ABC")));
+        assert_eq!(Answer::from(Some(7usize)), Answer::Number(7));
+        assert_eq!(Answer::from(None::<u64>), Answer::Unsolved);
+        assert_eq!(Answer::from(Some("ABC")).to_string(), "ABC");
+    }
+}
@@ -0,0 +1,34 @@
+/// Small seeded random number generator for tests that generate random inputs
+///
+/// Note: Not random at all, the same seed always gives the same numbers so failing tests can be reproduced
+pub struct Lcg {
+    state: u64
+}
+
+impl Lcg {
+    pub fn new(seed: u64) -> Lcg {
+        return Lcg { state: seed };
+    }
+
+    /// Next number in `0..n`
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.state = self.state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        return (self.state >> 33) % n;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lcg() {
+        let mut first = Lcg::new(3);
+        let mut second = Lcg::new(3);
+
+        let numbers: Vec<u64> = (0..100).map(|_| first.below(10)).collect();
+        assert_eq!(numbers, (0..100).map(|_| second.below(10)).collect::<Vec<u64>>());
+        assert!(numbers.iter().all(|n| *n < 10));
+        assert!((0..10).all(|n| numbers.contains(&n)));
+    }
+}